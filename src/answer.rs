@@ -0,0 +1,131 @@
+use num_bigint::BigInt;
+use std::fmt;
+
+/// The answer to either part of a day, regardless of what type the solution computed it as.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Answer {
+    /// A number of any width, so no solution has to worry about what fits in a `u64`.
+    Integer(BigInt),
+    /// A single line of text, like the crates on top of each stack.
+    Text(String),
+    /// Text spread over several lines, like letters drawn on a screen, which has to be read
+    /// before it can be submitted.
+    Art(String),
+}
+
+impl Answer {
+    /// Creates a multi-line answer from its lines.
+    pub fn art<I, S>(lines: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let mut art = String::new();
+        for (idx, line) in lines.into_iter().enumerate() {
+            if idx > 0 {
+                art.push('\n');
+            }
+            art.push_str(line.as_ref());
+        }
+        Answer::Art(art)
+    }
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Integer(n) => write!(f, "{n}"),
+            Answer::Text(s) | Answer::Art(s) => f.write_str(s),
+        }
+    }
+}
+
+/// Compares the answer to one given by a user, e.g. recorded in a file or typed on the command
+/// line, forgiving differences in formatting that don't change what the answer is.
+///
+/// Integers compare by value, so `+042` matches `42`. Text ignores surrounding whitespace. Art
+/// ignores trailing whitespace on each line, and blank lines around it.
+impl PartialEq<str> for Answer {
+    fn eq(&self, other: &str) -> bool {
+        match self {
+            Answer::Integer(n) => other.trim().parse::<BigInt>().is_ok_and(|o| *n == o),
+            Answer::Text(s) => s.trim() == other.trim(),
+            Answer::Art(s) => art_lines(s).eq(art_lines(other)),
+        }
+    }
+}
+
+impl PartialEq<&str> for Answer {
+    fn eq(&self, other: &&str) -> bool {
+        self == *other
+    }
+}
+
+impl PartialEq<String> for Answer {
+    fn eq(&self, other: &String) -> bool {
+        self == other.as_str()
+    }
+}
+
+fn art_lines(s: &str) -> impl Iterator<Item = &str> {
+    s.trim_matches(|c| c == '\n' || c == '\r')
+        .lines()
+        .map(str::trim_end)
+}
+
+macro_rules! from_integer {
+    ($($ty:ty),*) => {
+        $(
+            impl From<$ty> for Answer {
+                fn from(n: $ty) -> Self {
+                    Answer::Integer(n.into())
+                }
+            }
+        )*
+    };
+}
+
+from_integer!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, BigInt);
+
+impl From<String> for Answer {
+    fn from(s: String) -> Self {
+        Answer::Text(s)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(s: &str) -> Self {
+        Answer::Text(s.to_owned())
+    }
+}
+
+impl From<char> for Answer {
+    fn from(c: char) -> Self {
+        Answer::Text(c.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn displays() {
+        assert_eq!(Answer::from(u128::MAX).to_string(), u128::MAX.to_string());
+        assert_eq!(Answer::from(-12i64).to_string(), "-12");
+        assert_eq!(Answer::from("CMZ").to_string(), "CMZ");
+        assert_eq!(Answer::art(["#..#", "####"]).to_string(), "#..#\n####");
+    }
+
+    #[test]
+    fn compares_to_strings() {
+        assert_eq!(Answer::from(42u64), "42");
+        assert_eq!(Answer::from(42u64), " +042\n");
+        assert_ne!(Answer::from(42u64), "4 2");
+        assert_eq!(Answer::from("CMZ"), "CMZ\n");
+        assert_ne!(Answer::from("CMZ"), "cmz");
+        assert_eq!(Answer::art(["#..#", "####"]), "\n#..#  \n####\n".to_owned());
+        assert_ne!(Answer::art(["#..#", "####"]), "#..#");
+    }
+}
@@ -0,0 +1,165 @@
+use crate::{
+    days::{Day, Part},
+    Answer,
+};
+use eyre::{Result, WrapErr as _};
+use serde::{Deserialize, Serialize};
+use std::{collections::BTreeMap, fs, io, path::Path};
+
+/// Where the recorded answers live, relative to the current directory, which `aoc` expects to
+/// be the crate root.
+pub const ANSWERS_FILE: &str = "answers.toml";
+
+/// The known-correct answers for each day, as stored in `answers.toml`:
+///
+/// ```toml
+/// [1]
+/// part1 = "71506"
+/// part2 = "209603"
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Answers(BTreeMap<u8, DayAnswers>);
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DayAnswers {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub part1: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub part2: Option<String>,
+}
+
+impl Answers {
+    /// Loads the answers from a file, which is treated as empty if it does not exist yet.
+    pub fn load(path: &Path) -> Result<Self> {
+        match fs::read_to_string(path) {
+            Ok(toml) => toml::from_str(&toml)
+                .wrap_err_with(|| format!("invalid answers in {}", path.display())),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(Answers::default()),
+            Err(err) => {
+                Err(err).wrap_err_with(|| format!("could not read answers from {}", path.display()))
+            }
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> Result<()> {
+        fs::write(path, toml::to_string(self)?)
+            .wrap_err_with(|| format!("could not write answers to {}", path.display()))
+    }
+
+    pub fn get(&self, day: u8, part: Part) -> Option<&str> {
+        let answers = self.0.get(&day)?;
+        match part {
+            Part::One => answers.part1.as_deref(),
+            Part::Two => answers.part2.as_deref(),
+        }
+    }
+
+    /// Adds an entry for a day without any answers yet, unless it already has one.
+    pub fn add_day(&mut self, day: u8) {
+        self.0.entry(day).or_default();
+    }
+
+    pub fn record(&mut self, day: u8, part: Part, answer: &Answer) {
+        let answers = self.0.entry(day).or_default();
+        let slot = match part {
+            Part::One => &mut answers.part1,
+            Part::Two => &mut answers.part2,
+        };
+        *slot = Some(answer.to_string());
+    }
+}
+
+/// How a computed answer compares to the recorded one.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    Wrong { expected: String },
+    Unrecorded,
+}
+
+/// The outcome of checking a single part of a day against its recorded answer.
+#[derive(Debug)]
+pub struct Verification {
+    pub day: u8,
+    pub part: Part,
+    /// The computed answer and its verdict, or why no answer could be computed.
+    pub outcome: Result<(Answer, Verdict)>,
+}
+
+impl Verification {
+    /// Whether this should fail verification: a wrong answer, or no answer at all.
+    pub fn failed(&self) -> bool {
+        !matches!(
+            self.outcome,
+            Ok((_, Verdict::Correct | Verdict::Unrecorded))
+        )
+    }
+}
+
+/// Solves both parts of a day and compares the answers to the recorded ones.
+pub fn verify(day: &Day, input: &str, answers: &Answers) -> Vec<Verification> {
+    let parsed = day.parse(input);
+    Part::BOTH
+        .into_iter()
+        .map(|part| {
+            let outcome = match &parsed {
+                Ok(parsed) => day.solve(&**parsed, part).map(|answer| {
+                    let verdict = match answers.get(day.number, part) {
+                        Some(expected) if answer == expected => Verdict::Correct,
+                        Some(expected) => Verdict::Wrong {
+                            expected: expected.to_owned(),
+                        },
+                        None => Verdict::Unrecorded,
+                    };
+                    (answer, verdict)
+                }),
+                Err(err) => Err(eyre::eyre!("could not parse input: {err}")),
+            };
+            Verification {
+                day: day.number,
+                part,
+                outcome,
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::days;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn round_trips_through_toml() {
+        let mut answers = Answers::default();
+        answers.record(10, Part::Two, &Answer::Text("ABC".into()));
+        answers.record(2, Part::One, &Answer::from(15u64));
+        let toml = toml::to_string(&answers).unwrap();
+        assert_eq!(toml, "[2]\npart1 = \"15\"\n\n[10]\npart2 = \"ABC\"\n");
+        assert_eq!(toml::from_str::<Answers>(&toml).unwrap(), answers);
+    }
+
+    #[test]
+    fn verifies_against_recorded() {
+        let mut answers = Answers::default();
+        answers.record(6, Part::One, &Answer::from(7u64));
+        answers.record(6, Part::Two, &Answer::from(20u64));
+        let verifications = verify(
+            days::get(6).unwrap(),
+            "mjqjpqmgbljsphdztnvjfqwrcgsmlb",
+            &answers,
+        );
+        assert_eq!(
+            verifications[0].outcome.as_ref().unwrap().1,
+            Verdict::Correct
+        );
+        assert_eq!(
+            verifications[1].outcome.as_ref().unwrap().1,
+            Verdict::Wrong {
+                expected: "20".into()
+            },
+        );
+    }
+}
@@ -0,0 +1,221 @@
+use crate::days::{Day, Part};
+use eyre::{Result, WrapErr as _};
+use serde::{Deserialize, Serialize};
+use std::{
+    any::Any,
+    fmt, fs,
+    hint::black_box,
+    path::Path,
+    time::{Duration, Instant},
+};
+
+/// A step of solving a day that is timed on its own.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Phase {
+    Parse,
+    Part1,
+    Part2,
+}
+
+impl Phase {
+    pub const ALL: [Phase; 3] = [Phase::Parse, Phase::Part1, Phase::Part2];
+}
+
+impl fmt::Display for Phase {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Phase::Parse => "parse",
+            Phase::Part1 => "part1",
+            Phase::Part2 => "part2",
+        })
+    }
+}
+
+/// A summary of how long many iterations of a phase took, in nanoseconds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Stats {
+    pub min_ns: u64,
+    pub median_ns: u64,
+    pub p99_ns: u64,
+}
+
+impl Stats {
+    /// Summarises the given samples. There must be at least one.
+    pub fn from_samples(mut samples: Vec<Duration>) -> Self {
+        assert!(!samples.is_empty(), "cannot summarise zero samples");
+        samples.sort_unstable();
+        let percentile = |p: usize| {
+            let idx = (samples.len() * p).div_ceil(100).saturating_sub(1);
+            u64::try_from(samples[idx].as_nanos()).unwrap_or(u64::MAX)
+        };
+        Stats {
+            min_ns: percentile(0),
+            median_ns: percentile(50),
+            p99_ns: percentile(99),
+        }
+    }
+}
+
+/// The timings of a single phase of a single day.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Timing {
+    pub day: u8,
+    pub phase: Phase,
+    #[serde(flatten)]
+    pub stats: Stats,
+}
+
+/// A set of timings, as saved to and loaded from a baseline file.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Report {
+    pub iterations: usize,
+    pub timings: Vec<Timing>,
+}
+
+impl Report {
+    pub fn load(path: &Path) -> Result<Self> {
+        let json = fs::read_to_string(path)
+            .wrap_err_with(|| format!("could not read baseline from {}", path.display()))?;
+        serde_json::from_str(&json)
+            .wrap_err_with(|| format!("invalid baseline in {}", path.display()))
+    }
+
+    pub fn save(&self, path: &Path) -> Result<()> {
+        let json = serde_json::to_string_pretty(self)?;
+        fs::write(path, json + "\n")
+            .wrap_err_with(|| format!("could not write baseline to {}", path.display()))
+    }
+
+    pub fn get(&self, day: u8, phase: Phase) -> Option<&Stats> {
+        self.timings
+            .iter()
+            .find(|t| t.day == day && t.phase == phase)
+            .map(|t| &t.stats)
+    }
+
+    /// Finds every phase whose median got slower than in the baseline by more than `threshold`,
+    /// a fraction (so `0.1` is 10%).
+    pub fn regressions(&self, baseline: &Report, threshold: f64) -> Vec<Regression> {
+        self.timings
+            .iter()
+            .filter_map(|current| {
+                let before = baseline.get(current.day, current.phase)?;
+                let change = current.stats.median_ns as f64 / before.median_ns.max(1) as f64 - 1.0;
+                (change > threshold).then_some(Regression {
+                    day: current.day,
+                    phase: current.phase,
+                    before_ns: before.median_ns,
+                    after_ns: current.stats.median_ns,
+                    change,
+                })
+            })
+            .collect()
+    }
+}
+
+/// A phase whose median time is slower than its baseline.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Regression {
+    pub day: u8,
+    pub phase: Phase,
+    pub before_ns: u64,
+    pub after_ns: u64,
+    /// How much slower it got, as a fraction of the baseline.
+    pub change: f64,
+}
+
+/// Times every phase of a day over `iterations` runs each, after an untimed run to warm up
+/// caches and lazily initialised state, which would otherwise skew the slowest samples.
+pub fn measure(day: &Day, input: &str, iterations: usize) -> Result<Vec<Timing>> {
+    let iterations = iterations.max(1);
+    let parsed = day.parse(input)?;
+    let run = |phase| -> Result<Box<dyn Any>> {
+        Ok(match phase {
+            Phase::Parse => black_box(day.parse(black_box(input))?),
+            Phase::Part1 => Box::new(black_box(day.solve(&*parsed, Part::One)?)),
+            Phase::Part2 => Box::new(black_box(day.solve(&*parsed, Part::Two)?)),
+        })
+    };
+    let timing = |phase| -> Result<Timing> {
+        run(phase)?;
+        let mut samples = Vec::with_capacity(iterations);
+        for _ in 0..iterations {
+            let start = Instant::now();
+            // Whatever was computed is dropped after the clock stops.
+            let _output = run(phase)?;
+            samples.push(start.elapsed());
+        }
+        Ok(Timing {
+            day: day.number,
+            phase,
+            stats: Stats::from_samples(samples),
+        })
+    };
+    Phase::ALL.into_iter().map(timing).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    fn timing(day: u8, phase: Phase, median_ns: u64) -> Timing {
+        Timing {
+            day,
+            phase,
+            stats: Stats {
+                min_ns: median_ns,
+                median_ns,
+                p99_ns: median_ns,
+            },
+        }
+    }
+
+    #[test]
+    fn stats_from_samples() {
+        let samples = (1..=200).rev().map(Duration::from_nanos).collect();
+        assert_eq!(
+            Stats::from_samples(samples),
+            Stats {
+                min_ns: 1,
+                median_ns: 100,
+                p99_ns: 198,
+            },
+        );
+        assert_eq!(
+            Stats::from_samples(vec![Duration::from_nanos(7)]),
+            Stats {
+                min_ns: 7,
+                median_ns: 7,
+                p99_ns: 7,
+            },
+        );
+    }
+
+    #[test]
+    fn finds_regressions() {
+        let baseline = Report {
+            iterations: 10,
+            timings: vec![timing(1, Phase::Parse, 100), timing(1, Phase::Part1, 100)],
+        };
+        let current = Report {
+            iterations: 10,
+            timings: vec![
+                timing(1, Phase::Parse, 105),
+                timing(1, Phase::Part1, 150),
+                timing(2, Phase::Parse, 1000),
+            ],
+        };
+        let regressions = current.regressions(&baseline, 0.1);
+        assert_eq!(regressions.len(), 1);
+        assert_eq!(
+            (
+                regressions[0].day,
+                regressions[0].phase,
+                regressions[0].change
+            ),
+            (1, Phase::Part1, 0.5),
+        );
+    }
+}
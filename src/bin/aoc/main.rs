@@ -0,0 +1,431 @@
+use aoc2022::{
+    answers::{self, Answers, Verdict, ANSWERS_FILE},
+    bench::{self, Report},
+    days::{Part, Selection, DAYS},
+    gen,
+    http::{self, Client, SESSION_VAR},
+    input,
+    output::{Format, Record},
+    parallel, report, scaffold,
+    submit::{Outcome, Submitter, SUBMISSIONS_FILE},
+    watch::{self, Change, Watcher},
+};
+use clap::{Parser, Subcommand};
+use eyre::{bail, eyre, Result};
+use std::{
+    io::{self, Write as _},
+    panic,
+    path::{Path, PathBuf},
+    time::{Duration, Instant},
+};
+
+use trace::Trace;
+
+mod trace;
+
+#[derive(Parser)]
+#[command(about = "Advent of Code 2022 solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+    /// Trace what the solutions do, to `stderr` or to a chrome trace JSON file. `RUST_LOG`
+    /// picks what is traced, and turns on tracing to stderr by itself.
+    #[arg(long, global = true, value_name = "stderr|FILE")]
+    trace: Option<Trace>,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Runs one day, or all of them, and prints the answers.
+    Run {
+        /// A day number, or `all`.
+        days: Selection,
+        /// Only run this part (1 or 2).
+        #[arg(long)]
+        part: Option<Part>,
+        /// Read the input from this file, or `-` for stdin, instead of `inputs/dayN.txt`.
+        #[arg(long)]
+        input: Option<PathBuf>,
+        /// How to print the answers: plain, json or tsv.
+        #[arg(long, default_value = "plain")]
+        format: Format,
+        /// Run the days on this many threads at once, and sum them up in a table at the end.
+        /// Defaults to one per core when given without a number.
+        #[arg(long, short, value_name = "N", num_args = 0..=1, default_missing_value = "0")]
+        jobs: Option<usize>,
+    },
+    /// Lists the implemented days.
+    List,
+    /// Creates the module for a new day, and registers it with the runner and tests.
+    New { day: u8 },
+    /// Checks the answers of one day, or all of them, against those recorded in `answers.toml`.
+    Verify {
+        /// A day number, or `all`.
+        #[arg(default_value = "all")]
+        days: Selection,
+        /// Record the computed answers as the correct ones, instead of failing when they differ.
+        #[arg(long)]
+        record: bool,
+    },
+    /// Solves a part of a day, and submits the answer to the website.
+    ///
+    /// Every attempt is logged in `submissions.jsonl`, and answers known to be wrong from it are
+    /// never submitted again.
+    Submit {
+        day: u8,
+        /// The part to submit (1 or 2).
+        part: Part,
+        /// Read the input from this file, or `-` for stdin, instead of `inputs/dayN.txt`.
+        #[arg(long)]
+        input: Option<PathBuf>,
+    },
+    /// Times parsing and both parts of one day, or all of them.
+    Bench {
+        /// A day number, or `all`.
+        #[arg(default_value = "all")]
+        days: Selection,
+        /// How many times to run each phase.
+        #[arg(long, default_value_t = 100)]
+        iterations: usize,
+        /// Save the timings as a baseline JSON file.
+        #[arg(long)]
+        save: Option<PathBuf>,
+        /// Compare the timings against a baseline saved earlier, failing on any regression.
+        #[arg(long)]
+        baseline: Option<PathBuf>,
+        /// How much slower (in percent) a median may get before it counts as a regression.
+        #[arg(long, default_value_t = 10.0)]
+        threshold: f64,
+    },
+    /// Makes up an input for a day and prints it, to test solutions at scale or share inputs.
+    Gen {
+        day: u8,
+        /// The same seed always gives the same input.
+        #[arg(long, default_value_t = 0)]
+        seed: u64,
+        /// How big the input should be, like the number of elves for day 1. Defaults to about as
+        /// big as a real input.
+        #[arg(long)]
+        size: Option<usize>,
+    },
+    /// Runs a day on its input and fixtures, then again whenever they change, showing how the
+    /// answers changed since the last run.
+    Watch {
+        day: u8,
+        /// Poll for changes instead of relying on the OS to report them, which happens anyway
+        /// when it can't.
+        #[arg(long)]
+        poll: bool,
+        /// How often to poll, in milliseconds.
+        #[arg(long, default_value_t = 500)]
+        interval: u64,
+    },
+    /// Describes a day's input in more depth than its answers do, like statistics and rankings.
+    Report {
+        day: u8,
+        /// How many entries to list in rankings.
+        #[arg(long, default_value_t = 10)]
+        top: usize,
+        /// Read the input from this file, or `-` for stdin, instead of `inputs/dayN.txt`.
+        #[arg(long)]
+        input: Option<PathBuf>,
+    },
+}
+
+fn main() -> Result<()> {
+    color_eyre::install()?;
+    let cli = Cli::parse();
+    let _trace = trace::init(cli.trace)?;
+
+    match cli.command {
+        Command::Run {
+            days,
+            part,
+            input: input_path,
+            format,
+            jobs,
+        } => {
+            if input_path.is_some() && days == Selection::All {
+                bail!("--input can only be used when running a single day");
+            }
+
+            let parts = match part {
+                Some(part) => vec![part],
+                None => Part::BOTH.to_vec(),
+            };
+            if let Some(jobs) = jobs {
+                let jobs = match jobs {
+                    0 => parallel::default_jobs(),
+                    jobs => jobs,
+                };
+                let days = days.days()?;
+                // Panics are reported in the summary instead, rather than interleaved with it.
+                let previous = panic::take_hook();
+                panic::set_hook(Box::new(|_| {}));
+                let start = Instant::now();
+                let runs = parallel::run(&days, &parts, jobs, |day| {
+                    input::load(day, input_path.as_deref())
+                });
+                panic::set_hook(previous);
+
+                match format {
+                    Format::Plain => print!("{}", parallel::summary(&runs, &parts)),
+                    _ => {
+                        if let Some(header) = format.header() {
+                            println!("{header}");
+                        }
+                        for run in &runs {
+                            match &run.outcome {
+                                Ok(records) => {
+                                    for record in records {
+                                        println!("{}", format.format(record));
+                                    }
+                                }
+                                Err(err) => eprintln!("Day {}: {err}", run.day),
+                            }
+                        }
+                    }
+                }
+                // On stderr, so it never mixes in with JSON lines or TSV.
+                eprintln!(
+                    "total wall time: {:.2?} on {jobs} thread(s)",
+                    start.elapsed()
+                );
+
+                let failed = runs.iter().filter(|run| run.outcome.is_err()).count();
+                if failed > 0 {
+                    bail!("{failed} day(s) failed");
+                }
+                return Ok(());
+            }
+
+            if let Some(header) = format.header() {
+                println!("{header}");
+            }
+            for day in days.days()? {
+                let parsed = day.parse(&input::load(day, input_path.as_deref())?)?;
+                for &part in &parts {
+                    let start = Instant::now();
+                    let answer = day.solve(&*parsed, part)?;
+                    let record = Record::new(day.number, part, answer, start.elapsed());
+                    println!("{}", format.format(&record));
+                }
+            }
+        }
+        Command::List => {
+            for day in DAYS {
+                println!("{}", day.number);
+            }
+        }
+        Command::New { day } => {
+            for path in scaffold::generate(Path::new("."), day)? {
+                println!("wrote {}", path.display());
+            }
+        }
+        Command::Verify { days, record } => {
+            let path = Path::new(ANSWERS_FILE);
+            // Without it every part would be unrecorded, which passes, so it's most likely that
+            // this was run from somewhere other than the crate root.
+            if !record && !path.exists() {
+                bail!(
+                    "could not find {} in the current directory: run from the crate root, or \
+                     pass --record to start recording answers",
+                    path.display()
+                );
+            }
+            let mut recorded = Answers::load(path)?;
+
+            let mut failures = 0;
+            for day in days.days()? {
+                let input = input::load(day, None)?;
+                for verification in answers::verify(day, &input, &recorded) {
+                    let (day, part) = (verification.day, verification.part);
+                    // Recording replaces a wrong answer, so then only parts without one fail.
+                    if verification.failed() && (!record || verification.outcome.is_err()) {
+                        failures += 1;
+                    }
+                    let status = match &verification.outcome {
+                        Ok((answer, Verdict::Correct)) => format!("ok ({answer})"),
+                        Ok((answer, verdict)) if record => {
+                            recorded.record(day, part, answer);
+                            match verdict {
+                                Verdict::Wrong { expected } => {
+                                    format!("recorded {answer} (was {expected})")
+                                }
+                                _ => format!("recorded {answer}"),
+                            }
+                        }
+                        Ok((answer, Verdict::Wrong { expected })) => {
+                            format!("WRONG, got {answer} but expected {expected}")
+                        }
+                        Ok((answer, Verdict::Unrecorded)) => format!("unrecorded ({answer})"),
+                        Err(err) => format!("FAILED, {err}"),
+                    };
+                    println!("Day {day}, part {part}: {status}");
+                }
+            }
+
+            if record {
+                recorded.save(path)?;
+            }
+            if failures > 0 {
+                bail!("{failures} part(s) failed verification");
+            }
+        }
+        Command::Submit {
+            day,
+            part,
+            input: input_path,
+        } => {
+            let day = Selection::Day(day).days()?[0];
+            let session = http::session_from_env()
+                .ok_or_else(|| eyre!("{SESSION_VAR} must be set to submit answers"))?;
+
+            let answer = day.run(&input::load(day, input_path.as_deref())?, part)?;
+            let mut submitter =
+                Submitter::new(Client::new(session), PathBuf::from(SUBMISSIONS_FILE))?;
+            let outcome = submitter.submit(day.number, part, &answer)?;
+            println!("Day {}, part {part}: {answer} was {outcome}", day.number);
+
+            if outcome == Outcome::Correct {
+                let path = Path::new(ANSWERS_FILE);
+                let mut recorded = Answers::load(path)?;
+                recorded.record(day.number, part, &answer);
+                recorded.save(path)?;
+            }
+        }
+        Command::Bench {
+            days,
+            iterations,
+            save,
+            baseline,
+            threshold,
+        } => {
+            let baseline = baseline.as_deref().map(Report::load).transpose()?;
+
+            let mut report = Report {
+                iterations,
+                timings: Vec::new(),
+            };
+            println!(
+                "{:>3}  {:<5}  {:>12}  {:>12}  {:>12}",
+                "day", "phase", "min", "median", "p99"
+            );
+            for day in days.days()? {
+                let input = input::load(day, None)?;
+                for timing in bench::measure(day, &input, iterations)? {
+                    let stats = timing.stats;
+                    println!(
+                        "{:>3}  {:<5}  {:>12}  {:>12}  {:>12}",
+                        timing.day,
+                        timing.phase.to_string(),
+                        nanos(stats.min_ns),
+                        nanos(stats.median_ns),
+                        nanos(stats.p99_ns),
+                    );
+                    report.timings.push(timing);
+                }
+            }
+
+            if let Some(path) = save {
+                report.save(&path)?;
+            }
+            if let Some(baseline) = baseline {
+                let regressions = report.regressions(&baseline, threshold / 100.0);
+                for r in &regressions {
+                    println!(
+                        "regression: day {} {} went from {} to {} (+{:.1}%)",
+                        r.day,
+                        r.phase,
+                        nanos(r.before_ns),
+                        nanos(r.after_ns),
+                        r.change * 100.0,
+                    );
+                }
+                if !regressions.is_empty() {
+                    bail!("{} regression(s) against the baseline", regressions.len());
+                }
+            }
+        }
+        Command::Gen { day, seed, size } => {
+            let generator = gen::get(day)?;
+            let input = generator.generate(seed, size.unwrap_or(generator.default_size));
+            io::stdout().lock().write_all(input.as_bytes())?;
+        }
+        Command::Watch {
+            day,
+            poll,
+            interval,
+        } => {
+            let day = Selection::Day(day).days()?[0];
+            let watcher = Watcher::new(
+                &watch::watched_paths(day),
+                poll,
+                Duration::from_millis(interval),
+            )?;
+            let how = if watcher.polling() {
+                "polling"
+            } else {
+                "watching"
+            };
+            println!(
+                "{how} for changes to the input and fixtures of day {}",
+                day.number
+            );
+
+            let mut last = None;
+            loop {
+                // Half-written fixtures are what watching is for, so they are reported, not fatal.
+                let sources = match watch::sources(day) {
+                    Ok(sources) => sources,
+                    Err(err) => {
+                        eprintln!("error: {err:#}");
+                        watcher.wait(None)?;
+                        println!();
+                        continue;
+                    }
+                };
+                let outcomes = watch::evaluate(day, &sources);
+                if outcomes.is_empty() {
+                    println!("nothing to run day {} on yet", day.number);
+                }
+                for change in watch::diff(last.as_deref().unwrap_or(&[]), &outcomes) {
+                    let line = match change {
+                        Change::Added(o) if last.is_none() => {
+                            format!("  {} {}: {}", o.source, o.phase, o.result)
+                        }
+                        Change::Same(_) => continue,
+                        Change::Added(o) => format!("+ {} {}: {}", o.source, o.phase, o.result),
+                        Change::Changed { before, now: o } => {
+                            format!("~ {} {}: {before} -> {}", o.source, o.phase, o.result)
+                        }
+                        Change::Removed(o) => format!("- {} {}: {}", o.source, o.phase, o.result),
+                    };
+                    println!("{line}");
+                }
+                if last.as_ref() == Some(&outcomes) {
+                    println!("no changes");
+                }
+                last = Some(outcomes);
+
+                watcher.wait(None)?;
+                println!();
+            }
+        }
+        Command::Report {
+            day,
+            top,
+            input: input_path,
+        } => {
+            let day = Selection::Day(day).days()?[0];
+            let input = input::load(day, input_path.as_deref())?;
+            print!("{}", report::report(day, &input, top)?);
+        }
+    }
+
+    Ok(())
+}
+
+fn nanos(ns: u64) -> String {
+    format!("{:.2?}", Duration::from_nanos(ns))
+}
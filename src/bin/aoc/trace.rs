@@ -0,0 +1,67 @@
+use eyre::{eyre, Result};
+use std::{
+    convert::Infallible,
+    env,
+    io::{self, IsTerminal as _},
+    path::PathBuf,
+    str::FromStr,
+};
+use tracing_chrome::{ChromeLayerBuilder, FlushGuard};
+use tracing_subscriber::{fmt::format::FmtSpan, prelude::*, EnvFilter};
+
+/// What to trace when `--trace` is given without `RUST_LOG`.
+const DEFAULT_FILTER: &str = "aoc2022=debug";
+
+/// Where to send traces of the spans and events in the solutions.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Trace {
+    /// Log them to stderr as they happen, with how long each span took.
+    Stderr,
+    /// Write them to a JSON file that `chrome://tracing` or Perfetto can open.
+    Chrome(PathBuf),
+}
+
+impl FromStr for Trace {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "stderr" => Trace::Stderr,
+            path => Trace::Chrome(PathBuf::from(path)),
+        })
+    }
+}
+
+/// Starts tracing if asked to, by `--trace` or by setting `RUST_LOG`, which then filters what is
+/// traced. The returned guard has to be kept until the end, to finish writing a chrome trace.
+pub fn init(trace: Option<Trace>) -> Result<Option<FlushGuard>> {
+    let filter = match (env::var("RUST_LOG"), &trace) {
+        (Ok(filter), _) => EnvFilter::try_new(filter)?,
+        (Err(_), Some(_)) => EnvFilter::new(DEFAULT_FILTER),
+        (Err(_), None) => return Ok(None),
+    };
+
+    match trace.unwrap_or(Trace::Stderr) {
+        Trace::Stderr => {
+            tracing_subscriber::fmt()
+                .with_env_filter(filter)
+                .with_writer(io::stderr)
+                .with_ansi(io::stderr().is_terminal())
+                .with_span_events(FmtSpan::CLOSE)
+                .try_init()
+                .map_err(|err| eyre!(err))?;
+            Ok(None)
+        }
+        Trace::Chrome(path) => {
+            let (layer, guard) = ChromeLayerBuilder::new()
+                .file(path)
+                .include_args(true)
+                .build();
+            tracing_subscriber::registry()
+                .with(filter)
+                .with(layer)
+                .try_init()?;
+            Ok(Some(guard))
+        }
+    }
+}
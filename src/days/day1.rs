@@ -0,0 +1,455 @@
+use crate::{
+    input::Input,
+    parsing::{self, Line},
+    Answer, ParseError, Solution,
+};
+use eyre::{bail, eyre, Result};
+use itertools::Itertools as _;
+use num_bigint::BigInt;
+use num_traits::ToPrimitive;
+use std::{
+    cmp::Reverse, collections::BinaryHeap, fmt, io::BufRead, marker::PhantomData, str::FromStr,
+};
+
+const DAY: u8 = 1;
+
+/// Counts calories in `T`, which is `u64` unless the elves might carry more than that.
+pub struct Day1<T = u64>(PhantomData<T>);
+
+impl<T: Calories> Solution for Day1<T> {
+    type Parsed = Vec<Elf<T>>;
+
+    fn parse(input: &Input<'_>) -> Result<Self::Parsed, ParseError> {
+        parse(input)
+    }
+
+    fn part1(elves: &Self::Parsed) -> Result<Answer> {
+        part1(elves).map(Into::into)
+    }
+
+    fn part2(elves: &Self::Parsed) -> Result<Answer> {
+        part2(elves).map(Into::into)
+    }
+}
+
+fn part1<T: Calories>(elves: &[Elf<T>]) -> Result<T> {
+    sum_of_top(elves, 1)
+}
+
+fn part2<T: Calories>(elves: &[Elf<T>]) -> Result<T> {
+    sum_of_top(elves, 3)
+}
+
+/// The sum of the `k` biggest totals.
+fn sum_of_top<T: Calories>(elves: &[Elf<T>], k: usize) -> Result<T> {
+    let mut top = TopK::new(k);
+    for elf in elves {
+        top.push(&elf.total);
+    }
+    let totals = top.into_sorted_vec();
+    if totals.len() < k {
+        bail!(
+            "the top {k} elves were asked for, but there are only {}",
+            totals.len()
+        );
+    }
+    totals
+        .into_iter()
+        .try_fold(T::default(), |sum, total| sum.checked_add(total))
+        .ok_or_else(|| eyre!("could not add elf totals"))
+}
+
+/// A number to count calories in. Anything wider than a `u64` can count the calories of inputs
+/// made up to be bigger than real ones, which would not fit in one.
+pub trait Calories:
+    Clone + Default + Ord + FromStr + fmt::Display + ToPrimitive + Into<Answer> + Into<BigInt>
+{
+    /// `self + other`, unless that doesn't fit.
+    fn checked_add(&self, other: &Self) -> Option<Self>;
+}
+
+macro_rules! calories {
+    ($($ty:ty),*) => {
+        $(
+            impl Calories for $ty {
+                fn checked_add(&self, other: &Self) -> Option<Self> {
+                    <$ty>::checked_add(*self, *other)
+                }
+            }
+        )*
+    };
+}
+
+calories!(u64, u128);
+
+impl Calories for BigInt {
+    fn checked_add(&self, other: &Self) -> Option<Self> {
+        Some(self + other)
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Elf<T = u64> {
+    total: T,
+    items: Vec<T>,
+}
+
+impl<T> Elf<T> {
+    /// The calories of everything the elf carries.
+    pub fn total(&self) -> &T {
+        &self.total
+    }
+
+    /// The calories of each item, in the order they were listed.
+    pub fn items(&self) -> &[T] {
+        &self.items
+    }
+}
+
+fn parse<T: Calories>(input: &Input<'_>) -> Result<Vec<Elf<T>>, ParseError> {
+    // Each elf's items are a group of lines, and the groups are separated by blank lines.
+    let mut vec = Vec::new();
+    for group in parsing::groups(input, DAY) {
+        let mut elf = Elf::default();
+        for mut line in group {
+            let calories = item(&mut line, &mut elf.total)?;
+            elf.items.push(calories);
+        }
+        vec.push(elf);
+    }
+    Ok(vec)
+}
+
+/// Parses a line with the calories of an item, and adds them to its elf's `total`.
+fn item<T: Calories>(line: &mut Line<'_>, total: &mut T) -> Result<T, ParseError> {
+    const EXPECTED: &str = "a number of calories";
+    line.trim();
+    let (calories, span) = line.spanned(|line| line.integer::<T>(EXPECTED))?;
+    // Only unsigned types rule out negative numbers by themselves.
+    if calories < T::default() {
+        return Err(line.error(span, EXPECTED));
+    }
+    line.end()?;
+    *total = total.checked_add(&calories).ok_or_else(|| {
+        line.error(
+            span,
+            format!("calories that fit in the elf's total of {total}"),
+        )
+    })?;
+    Ok(calories)
+}
+
+/// Finds the `k` biggest elf totals in an input read from `reader`, biggest first, without
+/// reading it all in: only the current line, the current elf's total and the top `k` totals are
+/// kept, so it works on inputs far bigger than memory.
+///
+/// The input is parsed just like by [`Day1`], including CRLF line endings.
+pub fn top_totals<T: Calories>(mut reader: impl BufRead, k: usize) -> Result<Vec<T>> {
+    let mut top = TopK::new(k);
+    let mut buf = String::new();
+    let mut total = None;
+    for idx in 0.. {
+        buf.clear();
+        if reader.read_line(&mut buf)? == 0 {
+            break;
+        }
+        let text = buf.strip_suffix('\n').unwrap_or(&buf);
+        let text = text.strip_suffix('\r').unwrap_or(text);
+        // A blank line ends the current elf, if there is one.
+        if text.trim().is_empty() {
+            top.extend(total.take());
+            continue;
+        }
+        item(
+            &mut Line::new(DAY, idx, text),
+            total.get_or_insert_with(T::default),
+        )?;
+    }
+    top.extend(total);
+    Ok(top.into_sorted_vec())
+}
+
+/// The `k` biggest of the values pushed into it, kept in a min-heap so that each push takes
+/// O(log k) time, and it never takes up more than O(k) memory.
+#[derive(Debug, Clone)]
+struct TopK<T> {
+    k: usize,
+    heap: BinaryHeap<Reverse<T>>,
+}
+
+impl<T: Ord> TopK<T> {
+    fn new(k: usize) -> Self {
+        TopK {
+            k,
+            // A huge `k` grows the heap as needed, rather than up front.
+            heap: BinaryHeap::with_capacity(k.min(1024)),
+        }
+    }
+
+    fn push(&mut self, value: T) {
+        if self.heap.len() < self.k {
+            self.heap.push(Reverse(value));
+        } else if self.heap.peek().is_some_and(|Reverse(min)| value > *min) {
+            self.heap.pop();
+            self.heap.push(Reverse(value));
+        }
+    }
+
+    /// The values kept, biggest first.
+    fn into_sorted_vec(self) -> Vec<T> {
+        self.heap
+            .into_sorted_vec()
+            .into_iter()
+            .map(|Reverse(value)| value)
+            .collect()
+    }
+}
+
+impl<T: Ord> Extend<T> for TopK<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, values: I) {
+        for value in values {
+            self.push(value);
+        }
+    }
+}
+
+/// Statistics of the elves' totals.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Stats<T = u64> {
+    pub count: usize,
+    pub min: T,
+    pub max: T,
+    pub mean: f64,
+    /// The middle total, or the mean of the middle two for an even count.
+    pub median: f64,
+    /// The population standard deviation.
+    pub std_dev: f64,
+    /// The totals sorted from smallest to biggest, for [`Stats::percentile`].
+    sorted: Vec<T>,
+}
+
+impl<T: Calories> Stats<T> {
+    /// Works out the statistics of `elves`, unless there aren't any.
+    pub fn new(elves: &[Elf<T>]) -> Option<Self> {
+        let mut sorted = elves.iter().map(|elf| elf.total.clone()).collect_vec();
+        sorted.sort_unstable();
+        let (min, max) = (sorted.first()?.clone(), sorted.last()?.clone());
+        let count = sorted.len();
+        // Summed as floats, as the totals of many elves can add up to more than fits in `T`.
+        let float = |total: &T| total.to_f64().unwrap_or(f64::INFINITY);
+        let mean = sorted.iter().map(float).sum::<f64>() / count as f64;
+        let variance = sorted
+            .iter()
+            .map(|total| (float(total) - mean).powi(2))
+            .sum::<f64>()
+            / count as f64;
+        let median = match count % 2 {
+            0 => (float(&sorted[count / 2 - 1]) + float(&sorted[count / 2])) / 2.0,
+            _ => float(&sorted[count / 2]),
+        };
+        Some(Stats {
+            count,
+            min,
+            max,
+            mean,
+            median,
+            std_dev: variance.sqrt(),
+            sorted,
+        })
+    }
+
+    /// The smallest total that at least `p` percent of the elves carry no more than, using the
+    /// nearest-rank method, so it is always one of the totals.
+    pub fn percentile(&self, p: f64) -> &T {
+        let rank = (p.clamp(0.0, 100.0) / 100.0 * self.count as f64).ceil() as usize;
+        &self.sorted[rank.saturating_sub(1)]
+    }
+}
+
+/// Where an elf places when ordered by their totals, biggest first.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rank<T = u64> {
+    /// 1-based, and shared by elves with the same total, so that after two elves tied for 1st,
+    /// the next is 3rd.
+    pub rank: usize,
+    /// The elf's 0-based position in the input.
+    pub index: usize,
+    pub total: T,
+}
+
+/// Ranks every elf, biggest total first, and the earlier elf first among those tied.
+pub fn ranking<T: Calories>(elves: &[Elf<T>]) -> Vec<Rank<T>> {
+    let mut ranking = elves
+        .iter()
+        .enumerate()
+        .map(|(index, elf)| Rank {
+            rank: 0,
+            index,
+            total: elf.total.clone(),
+        })
+        .collect_vec();
+    ranking.sort_by(|a, b| b.total.cmp(&a.total).then(a.index.cmp(&b.index)));
+    for i in 0..ranking.len() {
+        ranking[i].rank = match i.checked_sub(1) {
+            Some(prev) if ranking[prev].total == ranking[i].total => ranking[prev].rank,
+            _ => i + 1,
+        };
+    }
+    ranking
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+    use proptest::prelude::*;
+
+    #[test]
+    fn parse_correctly() {
+        assert_eq!(
+            parse(&Input::new("1\n1\n3\n\n3\n\n4")).unwrap(),
+            vec![elf([1, 1, 3]), elf([3]), elf([4])],
+        );
+    }
+
+    #[test]
+    fn parse_reports_error() {
+        let err = parse::<u64>(&Input::new("1\n2\n\n3\n4x\n")).unwrap_err();
+        assert_eq!((err.line, err.column, err.found.as_str()), (5, 1, "4x"));
+    }
+
+    #[test]
+    fn widens_totals_that_overflow() {
+        let input = Input::new("18446744073709551615\n1\n\n2\n\n3\n");
+        let err = parse::<u64>(&input).unwrap_err();
+        assert_eq!((err.line, err.found.as_str()), (2, "1"));
+
+        let elves = parse::<u128>(&input).unwrap();
+        assert_eq!(part1(&elves).unwrap(), 1 << 64);
+        assert_eq!(part2(&elves).unwrap(), (1 << 64) + 5);
+        let elves = parse::<BigInt>(&input).unwrap();
+        assert_eq!(part2(&elves).unwrap(), BigInt::from(u64::MAX) + 6);
+        assert_eq!(
+            top_totals::<BigInt>(input.as_str().as_bytes(), 1).unwrap(),
+            [BigInt::from(1u128 << 64)]
+        );
+
+        let day = crate::days::get(DAY).unwrap();
+        assert_eq!(
+            day.run(input.as_str(), crate::days::Part::One).unwrap(),
+            "18446744073709551616"
+        );
+
+        let err = parse::<BigInt>(&Input::new("1\n-2\n")).unwrap_err();
+        assert_eq!((err.line, err.column, err.found.as_str()), (2, 1, "-2"));
+    }
+
+    #[test]
+    fn streams_top_totals() {
+        let input = "1\r\n1\r\n3\r\n\r\n3\r\n\r\n\r\n4\r\n";
+        assert_eq!(top_totals::<u64>(input.as_bytes(), 2).unwrap(), [5, 4]);
+        assert_eq!(top_totals::<u64>(input.as_bytes(), 5).unwrap(), [5, 4, 3]);
+        assert!(top_totals::<u64>(input.as_bytes(), 0).unwrap().is_empty());
+
+        let err = top_totals::<u64>("1\n2\n\n3\n4x\n".as_bytes(), 3).unwrap_err();
+        let err = err.downcast::<ParseError>().unwrap();
+        assert_eq!((err.line, err.column, err.found.as_str()), (5, 1, "4x"));
+    }
+
+    #[test]
+    fn computes_statistics() {
+        let elves = [elf([2, 2]), elf([4]), elf([4]), elf([10])];
+        let stats = Stats::new(&elves).unwrap();
+        assert_eq!(
+            (stats.count, stats.min, stats.max, stats.mean, stats.median),
+            (4, 4, 10, 5.5, 4.0)
+        );
+        assert_eq!(stats.std_dev, 2.598076211353316);
+        assert_eq!(
+            [0.0, 25.0, 50.0, 51.0, 100.0].map(|p| *stats.percentile(p)),
+            [4, 4, 4, 4, 10]
+        );
+        assert_eq!(Stats::new(&elves[1..]).unwrap().median, 4.0);
+        assert_eq!(Stats::<u64>::new(&[]), None);
+
+        let input = Input::new("18446744073709551615\n1\n\n1\n");
+        let wide = Stats::new(&parse::<u128>(&input).unwrap()).unwrap();
+        assert_eq!((wide.min, wide.max), (1, 1 << 64));
+        assert_eq!(wide.mean, 2f64.powi(63));
+        assert_eq!(*wide.percentile(99.0), 1 << 64);
+    }
+
+    #[test]
+    fn ranks_ties_together() {
+        let elves = [elf([3]), elf([5]), elf([1, 2]), elf([1])];
+        let ranks = ranking(&elves)
+            .into_iter()
+            .map(|r| (r.rank, r.index, r.total))
+            .collect_vec();
+        assert_eq!(ranks, [(1, 1, 5), (2, 0, 3), (2, 2, 3), (4, 3, 1)]);
+    }
+
+    proptest! {
+        #[test]
+        fn parse_round_trips(elves in elves()) {
+            prop_assert_eq!(parse(&Input::new(&print(&elves))).unwrap(), elves);
+        }
+
+        #[test]
+        fn parts_match_naive(elves in elves()) {
+            prop_assert_eq!(part1(&elves).unwrap(), naive_part1(&elves));
+            prop_assert_eq!(part2(&elves).unwrap(), naive_part2(&elves));
+        }
+
+        #[test]
+        fn top_totals_match_sorting(elves in elves(), k in 0..60usize) {
+            let mut totals = elves.iter().map(|elf| elf.total).collect_vec();
+            totals.sort_unstable_by(|a, b| b.cmp(a));
+            totals.truncate(k);
+            prop_assert_eq!(top_totals::<u64>(print(&elves).as_bytes(), k).unwrap(), totals);
+        }
+    }
+
+    fn elf<const N: usize>(items: [u64; N]) -> Elf {
+        let items = items.to_vec();
+        Elf {
+            total: items.iter().sum(),
+            items,
+        }
+    }
+
+    /// At least three elves, so part 2 has an answer.
+    fn elves() -> impl Strategy<Value = Vec<Elf>> {
+        let elf = prop::collection::vec(0..100_000u64, 1..10).prop_map(|items| Elf {
+            total: items.iter().sum(),
+            items,
+        });
+        prop::collection::vec(elf, 3..50)
+    }
+
+    fn print(elves: &[Elf]) -> String {
+        elves
+            .iter()
+            .map(|elf| elf.items.iter().join("\n"))
+            .join("\n\n")
+    }
+
+    fn naive_part1(elves: &[Elf]) -> u64 {
+        elves
+            .iter()
+            .map(|elf| elf.items.iter().sum())
+            .max()
+            .unwrap()
+    }
+
+    /// Tries every group of three elves.
+    fn naive_part2(elves: &[Elf]) -> u64 {
+        elves
+            .iter()
+            .map(|elf| elf.items.iter().sum::<u64>())
+            .combinations(3)
+            .map(|three| three.into_iter().sum())
+            .max()
+            .unwrap()
+    }
+}
@@ -0,0 +1,453 @@
+use crate::{input::Input, parsing, Answer, ParseError, Solution};
+use eyre::{bail, Result};
+use itertools::Itertools as _;
+use std::iter;
+
+const DAY: u8 = 2;
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    type Parsed = Vec<Round>;
+
+    fn parse(input: &Input<'_>) -> Result<Self::Parsed, ParseError> {
+        parse(input)
+    }
+
+    fn part1(rounds: &Self::Parsed) -> Result<Answer> {
+        part1(rounds).map(Answer::from)
+    }
+
+    fn part2(rounds: &Self::Parsed) -> Result<Answer> {
+        part2(rounds).map(Answer::from)
+    }
+}
+
+/// The second column is the shape to play.
+fn part1(rounds: &[Round]) -> Result<u64> {
+    Guide::Shapes([Shape::ROCK, Shape::PAPER, Shape::SCISSORS])
+        .score(&Rules::rock_paper_scissors(), rounds)
+}
+
+/// The second column is how the round has to end.
+fn part2(rounds: &[Round]) -> Result<u64> {
+    Guide::Outcomes([Outcome::Lose, Outcome::Draw, Outcome::Win])
+        .score(&Rules::rock_paper_scissors(), rounds)
+}
+
+/// A shape to play, as its place in the cycle of shapes of the game being played, which has any
+/// odd number of them. [`Rules`] check that the shapes they are given are theirs, as a shape of
+/// a game with more of them may be out of range.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Shape(usize);
+
+impl Shape {
+    // Every game has at least three shapes, so the first three are always in range.
+    const ROCK: Shape = Shape(0);
+    const PAPER: Shape = Shape(1);
+    const SCISSORS: Shape = Shape(2);
+}
+
+/// How a round ends, for the one playing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Outcome {
+    Lose,
+    Draw,
+    Win,
+}
+
+impl Outcome {
+    pub const ALL: [Outcome; 3] = [Outcome::Lose, Outcome::Draw, Outcome::Win];
+}
+
+/// The rules of a game like rock paper scissors: which shape beats which, and how many points
+/// each shape and outcome are worth.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rules {
+    names: Vec<String>,
+    /// How a round ends for the shape of the row, against the shape of the column.
+    table: Vec<Vec<Outcome>>,
+    shape_points: Vec<u64>,
+    /// Indexed by [`Outcome`].
+    outcome_points: [u64; 3],
+}
+
+impl Rules {
+    /// A game where each shape beats the one before it in `names`, the one three before, and so
+    /// on for every odd distance, wrapping around. So every shape beats half of the others, and
+    /// is beaten by the other half, which takes an odd number of shapes.
+    ///
+    /// Shapes score their 1-based place in `names`, and a loss, draw and win score 0, 3 and 6,
+    /// as in the puzzle.
+    pub fn cyclic<S: Into<String>>(names: impl IntoIterator<Item = S>) -> Result<Self> {
+        let names: Vec<String> = names.into_iter().map(Into::into).collect();
+        let n = names.len();
+        if n < 3 || n.is_multiple_of(2) {
+            bail!("a cyclic game needs an odd number of shapes, at least 3, not {n}");
+        }
+        let table = (0..n)
+            .map(|us| {
+                (0..n)
+                    .map(|them| match (us + n - them) % n {
+                        0 => Outcome::Draw,
+                        distance if distance % 2 == 1 => Outcome::Win,
+                        _ => Outcome::Lose,
+                    })
+                    .collect()
+            })
+            .collect();
+        Ok(Rules {
+            names,
+            table,
+            shape_points: (1..=n as u64).collect(),
+            outcome_points: [0, 3, 6],
+        })
+    }
+
+    pub fn rock_paper_scissors() -> Self {
+        Rules::cyclic(["Rock", "Paper", "Scissors"]).expect("3 shapes make a cyclic game")
+    }
+
+    /// The variant with two more shapes, where Spock smashes scissors and vaporizes rock, and
+    /// lizard eats paper and poisons Spock.
+    pub fn rock_paper_scissors_lizard_spock() -> Self {
+        Rules::cyclic(["Rock", "Paper", "Scissors", "Spock", "Lizard"])
+            .expect("5 shapes make a cyclic game")
+    }
+
+    /// Scores each shape `points` instead, in the order of the shapes.
+    pub fn with_shape_points(mut self, points: impl IntoIterator<Item = u64>) -> Result<Self> {
+        let points: Vec<u64> = points.into_iter().collect();
+        if points.len() != self.names.len() {
+            bail!(
+                "{} shape points were given for {} shapes",
+                points.len(),
+                self.names.len()
+            );
+        }
+        self.shape_points = points;
+        Ok(self)
+    }
+
+    /// Scores a loss, draw and win these points instead.
+    pub fn with_outcome_points(mut self, lose: u64, draw: u64, win: u64) -> Self {
+        self.outcome_points = [lose, draw, win];
+        self
+    }
+
+    /// Every shape of the game, in order.
+    pub fn shapes(&self) -> impl Iterator<Item = Shape> + Clone {
+        (0..self.names.len()).map(Shape)
+    }
+
+    /// The shape called `name`, if the game has one.
+    pub fn shape(&self, name: &str) -> Option<Shape> {
+        self.names.iter().position(|n| n == name).map(Shape)
+    }
+
+    pub fn name(&self, shape: Shape) -> Result<&str> {
+        Ok(&self.names[self.index(shape)?])
+    }
+
+    /// How a round ends when `us` plays against `them`.
+    pub fn outcome(&self, us: Shape, them: Shape) -> Result<Outcome> {
+        Ok(self.table[self.index(us)?][self.index(them)?])
+    }
+
+    /// The first shape, in order, to play against `them` for the round to end in `outcome`.
+    pub fn shape_for(&self, them: Shape, outcome: Outcome) -> Result<Shape> {
+        let them = self.index(them)?;
+        Ok(self
+            .shapes()
+            .find(|us| self.table[us.0][them] == outcome)
+            .expect("every outcome is possible against every shape"))
+    }
+
+    /// The points `us` scores in a round against `them`.
+    pub fn score(&self, us: Shape, them: Shape) -> Result<u64> {
+        let outcome = self.outcome(us, them)?;
+        Ok(self.shape_points[us.0] + self.outcome_points[outcome as usize])
+    }
+
+    /// Where `shape` is in the tables, if it is one of this game's.
+    fn index(&self, shape: Shape) -> Result<usize> {
+        let n = self.names.len();
+        if shape.0 >= n {
+            bail!(
+                "shape {} is not one of the {n} shapes of this game",
+                shape.0 + 1
+            );
+        }
+        Ok(shape.0)
+    }
+}
+
+/// A line of the strategy guide: the opponent's shape, and the column of the second letter,
+/// which means something the guide doesn't say.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Round {
+    /// One of the first three shapes, which every game has, for `A`, `B` or `C`.
+    them: Shape,
+    /// 0, 1 or 2 for `X`, `Y` or `Z`.
+    column: usize,
+}
+
+/// What `X`, `Y` and `Z` in the strategy guide might mean.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Guide {
+    /// The shape to play.
+    Shapes([Shape; 3]),
+    /// How the round should end.
+    Outcomes([Outcome; 3]),
+}
+
+impl Guide {
+    /// The total score of following the guide, read this way, for every round.
+    pub fn score(&self, rules: &Rules, rounds: &[Round]) -> Result<u64> {
+        rounds
+            .iter()
+            .map(|round| {
+                let us = match self {
+                    Guide::Shapes(shapes) => shapes[round.column],
+                    Guide::Outcomes(outcomes) => {
+                        rules.shape_for(round.them, outcomes[round.column])?
+                    }
+                };
+                rules.score(us, round.them)
+            })
+            .sum()
+    }
+
+    /// Spells out what each column means, like `X Rock, Y Paper, Z Scissors`.
+    pub fn describe(&self, rules: &Rules) -> Result<String> {
+        let meanings: Vec<String> = match self {
+            Guide::Shapes(shapes) => shapes
+                .iter()
+                .map(|&s| rules.name(s).map(str::to_owned))
+                .try_collect()?,
+            Guide::Outcomes(outcomes) => outcomes.iter().map(|o| format!("{o:?}")).collect(),
+        };
+        Ok(["X", "Y", "Z"]
+            .iter()
+            .zip(meanings)
+            .map(|(column, meaning)| format!("{column} {meaning}"))
+            .join(", "))
+    }
+}
+
+/// A way to read the strategy guide, and what following it scores.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Interpretation {
+    pub guide: Guide,
+    pub score: u64,
+}
+
+/// Scores every way to read the guide: `X`, `Y` and `Z` as any shapes of the game, or as any
+/// outcomes, where columns may mean the same thing. The best come first, and those that score
+/// the same stay in the order above, shapes before outcomes.
+pub fn interpretations(rules: &Rules, rounds: &[Round]) -> Result<Vec<Interpretation>> {
+    let as_shapes = iter::repeat_n(rules.shapes(), 3)
+        .multi_cartesian_product()
+        .map(|shapes| Guide::Shapes([shapes[0], shapes[1], shapes[2]]));
+    let as_outcomes = iter::repeat_n(Outcome::ALL, 3)
+        .multi_cartesian_product()
+        .map(|outcomes| Guide::Outcomes([outcomes[0], outcomes[1], outcomes[2]]));
+    let mut interpretations: Vec<_> = as_shapes
+        .chain(as_outcomes)
+        .map(|guide| {
+            Ok(Interpretation {
+                guide,
+                score: guide.score(rules, rounds)?,
+            })
+        })
+        .collect::<Result<_>>()?;
+    interpretations.sort_by_key(|interpretation| std::cmp::Reverse(interpretation.score));
+    Ok(interpretations)
+}
+
+fn parse(input: &Input<'_>) -> Result<Vec<Round>, ParseError> {
+    parsing::each_line(input, DAY, |line| {
+        let them = Shape(line.one_of(&["A", "B", "C"])?);
+        line.keyword(" ")?;
+        let column = line.one_of(&["X", "Y", "Z"])?;
+        Ok(Round { them, column })
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+    use proptest::prelude::*;
+
+    #[test]
+    fn parse_correctly() {
+        assert_eq!(
+            parse(&Input::new("A X\nB Y\nC Z")).unwrap(),
+            [(0, 0), (1, 1), (2, 2)].map(round)
+        );
+    }
+
+    #[test]
+    fn parse_reports_error() {
+        let err = parse(&Input::new("A X\nB W")).unwrap_err();
+        assert_eq!((err.line, err.column, err.found.as_str()), (2, 3, "W"));
+        let err = parse(&Input::new("A X\nB")).unwrap_err();
+        assert_eq!((err.line, err.column, err.found.as_str()), (2, 2, ""));
+    }
+
+    #[test]
+    fn plays_rock_paper_scissors_lizard_spock() {
+        let rules = Rules::rock_paper_scissors_lizard_spock();
+        let (spock, lizard) = (
+            rules.shape("Spock").unwrap(),
+            rules.shape("Lizard").unwrap(),
+        );
+        let beats = |us| {
+            rules
+                .shapes()
+                .filter(|&them| rules.outcome(us, them).unwrap() == Outcome::Win)
+                .map(|them| rules.name(them).unwrap())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(beats(Shape::ROCK), ["Scissors", "Lizard"]);
+        assert_eq!(beats(spock), ["Rock", "Scissors"]);
+        assert_eq!(beats(lizard), ["Paper", "Spock"]);
+        assert_eq!(rules.shape_for(spock, Outcome::Win).unwrap(), Shape::PAPER);
+        assert_eq!(Rules::rock_paper_scissors().shape("Lizard"), None);
+
+        let rules = rules
+            .with_shape_points([0; 5])
+            .unwrap()
+            .with_outcome_points(0, 1, 2);
+        let guide = Guide::Shapes([lizard, spock, Shape::ROCK]);
+        assert_eq!(
+            guide
+                .score(&rules, &[(3, 0), (3, 1), (3, 2)].map(round))
+                .unwrap(),
+            3
+        );
+
+        // Shapes from a game with more of them don't fit in the classic one.
+        let classic = Rules::rock_paper_scissors();
+        assert!(classic.name(lizard).is_err());
+        assert!(guide.score(&classic, &[(0, 0)].map(round)).is_err());
+        assert!(guide.describe(&classic).is_err());
+
+        assert!(Rules::cyclic(["Rock", "Paper"]).is_err());
+        assert!(Rules::rock_paper_scissors().with_shape_points([1]).is_err());
+    }
+
+    #[test]
+    fn ranks_every_interpretation() {
+        let rules = Rules::rock_paper_scissors();
+        let rounds = [(0, 1), (1, 0), (2, 2)].map(round);
+        let ranked = interpretations(&rules, &rounds).unwrap();
+        assert_eq!(ranked.len(), 54);
+        assert!(ranked.windows(2).all(|pair| pair[0].score >= pair[1].score));
+
+        // Paper beats rock, scissors beat paper and rock beats scissors, with the most points.
+        let best = Guide::Shapes([Shape::SCISSORS, Shape::PAPER, Shape::ROCK]);
+        assert_eq!(
+            ranked[0],
+            Interpretation {
+                guide: best,
+                score: 24
+            }
+        );
+        assert_eq!(
+            best.describe(&rules).unwrap(),
+            "X Scissors, Y Paper, Z Rock"
+        );
+        let worst = ranked.last().unwrap();
+        assert_eq!(worst.score, 6);
+        assert_eq!(
+            Guide::Outcomes(Outcome::ALL).describe(&rules).unwrap(),
+            "X Lose, Y Draw, Z Win"
+        );
+
+        // Reading the guide like the puzzle does is one of them.
+        let part1 = Guide::Shapes([Shape::ROCK, Shape::PAPER, Shape::SCISSORS]);
+        assert!(ranked.contains(&Interpretation {
+            guide: part1,
+            score: part1.score(&rules, &rounds).unwrap()
+        }));
+        assert_eq!(
+            interpretations(&Rules::rock_paper_scissors_lizard_spock(), &rounds)
+                .unwrap()
+                .len(),
+            152
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn parse_round_trips(rounds in rounds()) {
+            prop_assert_eq!(parse(&Input::new(&print(&rounds))).unwrap(), rounds);
+        }
+
+        #[test]
+        fn parts_match_naive(rounds in rounds()) {
+            prop_assert_eq!(part1(&rounds).unwrap(), naive_part1(&rounds));
+            prop_assert_eq!(part2(&rounds).unwrap(), naive_part2(&rounds));
+        }
+    }
+
+    fn round((them, column): (usize, usize)) -> Round {
+        Round {
+            them: Shape(them),
+            column,
+        }
+    }
+
+    fn rounds() -> impl Strategy<Value = Vec<Round>> {
+        prop::collection::vec((0..3usize, 0..3usize).prop_map(round), 0..100)
+    }
+
+    fn print(rounds: &[Round]) -> String {
+        rounds
+            .iter()
+            .map(|round| {
+                format!(
+                    "{} {}\n",
+                    (b'A' + round.them.0 as u8) as char,
+                    (b'X' + round.column as u8) as char
+                )
+            })
+            .collect()
+    }
+
+    /// How the round went for us, in points, when `them` and `us` are shapes.
+    fn outcome(them: u64, us: u64) -> u64 {
+        // Each shape beats the one before it, wrapping around.
+        if them == us {
+            3
+        } else if us == (them + 1) % 3 {
+            6
+        } else {
+            0
+        }
+    }
+
+    fn naive_part1(rounds: &[Round]) -> u64 {
+        rounds
+            .iter()
+            .map(|round| {
+                let (them, us) = (round.them.0 as u64, round.column as u64);
+                us + 1 + outcome(them, us)
+            })
+            .sum()
+    }
+
+    /// Tries every shape until one gives the outcome we're after.
+    fn naive_part2(rounds: &[Round]) -> u64 {
+        rounds
+            .iter()
+            .map(|round| {
+                let (them, result) = (round.them.0 as u64, round.column as u64);
+                let us = (0..3).find(|&us| outcome(them, us) == result * 3).unwrap();
+                us + 1 + result * 3
+            })
+            .sum()
+    }
+}
@@ -0,0 +1,153 @@
+use crate::{input::Input, parsing, Answer, ParseError, Solution};
+use eyre::{eyre, Result};
+use itertools::Itertools as _;
+
+const DAY: u8 = 3;
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    type Parsed = Vec<String>;
+
+    fn parse(input: &Input<'_>) -> Result<Self::Parsed, ParseError> {
+        Ok(parse(input)?.into_iter().map(String::from).collect())
+    }
+
+    fn part1(rucksacks: &Self::Parsed) -> Result<Answer> {
+        part1(rucksacks).map(Answer::from)
+    }
+
+    fn part2(rucksacks: &Self::Parsed) -> Result<Answer> {
+        part2(rucksacks).map(Answer::from)
+    }
+}
+
+fn part1<S: AsRef<str>>(rucksack: &[S]) -> Result<i64> {
+    rucksack
+        .iter()
+        .map(AsRef::as_ref)
+        .map(|s| s.split_at(s.len() / 2))
+        .map(|(a, b)| {
+            a.chars()
+                .filter(|&c| b.contains(c))
+                .unique()
+                .collect_tuple::<(char,)>()
+                .ok_or_else(|| eyre!("could not find shared item in compartments: ({a}, {b})"))
+                .map(|(c,)| prio(c))
+        })
+        .sum()
+}
+
+fn part2<S: AsRef<str>>(rucksack: &[S]) -> Result<i64> {
+    rucksack
+        .iter()
+        .chunks(3)
+        .into_iter()
+        .map(|chunk| {
+            let (a, b, c) = chunk
+                .map(AsRef::as_ref)
+                .collect_tuple::<(&str, &str, &str)>()
+                .ok_or_else(|| eyre!("could not find 3 rucksacks in the last group"))?;
+            a.chars()
+                .filter(|&ch| b.contains(ch))
+                .filter(|&ch| c.contains(ch))
+                .unique()
+                .collect_tuple::<(char,)>()
+                .ok_or_else(|| eyre!("could not find shared item in rucksacks: ({a}, {b}, {c})"))
+                .map(|(ch,)| prio(ch))
+        })
+        .sum()
+}
+
+fn prio(c: char) -> i64 {
+    if c.is_lowercase() {
+        c as i64 - b'a' as i64 + 1
+    } else {
+        c as i64 - b'A' as i64 + 27
+    }
+}
+
+fn parse<'a>(input: &'a Input<'_>) -> Result<Vec<&'a str>, ParseError> {
+    parsing::each_line(input, DAY, |line| {
+        let items = line.take_while(|c| c.is_ascii_alphabetic());
+        if !line.is_empty() {
+            return Err(line.expected("an item from `a` to `Z`"));
+        }
+        if items.len() % 2 != 0 {
+            return Err(line.error(items, "an even number of items"));
+        }
+        Ok(items)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gen;
+    use pretty_assertions::assert_eq;
+    use proptest::prelude::*;
+
+    #[test]
+    fn parse_reports_error() {
+        let err = parse(&Input::new("abab\nabc")).unwrap_err();
+        assert_eq!((err.line, err.column, err.found.as_str()), (2, 1, "abc"));
+        let err = parse(&Input::new("ab-b")).unwrap_err();
+        assert_eq!((err.line, err.column, err.found.as_str()), (1, 3, "-"));
+    }
+
+    proptest! {
+        #[test]
+        fn parse_round_trips(rucksacks in rucksacks()) {
+            let input = rucksacks.join("\n");
+            let input = Input::new(&input);
+            prop_assert_eq!(parse(&input).unwrap(), rucksacks);
+        }
+
+        #[test]
+        fn parts_match_naive(rucksacks in rucksacks()) {
+            prop_assert_eq!(part1(&rucksacks).unwrap(), naive_part1(&rucksacks));
+            prop_assert_eq!(part2(&rucksacks).unwrap(), naive_part2(&rucksacks));
+        }
+    }
+
+    const ITEMS: &str = "abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+    /// Groups of three rucksacks, as the generator makes them.
+    fn rucksacks() -> impl Strategy<Value = Vec<String>> {
+        (any::<u64>(), 0..30usize).prop_map(|(seed, size)| {
+            let input = gen::get(DAY).unwrap().generate(seed, size);
+            input.lines().map(String::from).collect()
+        })
+    }
+
+    fn naive_prio(item: char) -> i64 {
+        ITEMS.find(item).unwrap() as i64 + 1
+    }
+
+    fn naive_part1(rucksacks: &[String]) -> i64 {
+        rucksacks
+            .iter()
+            .map(|rucksack| {
+                let (left, right) = rucksack.split_at(rucksack.len() / 2);
+                ITEMS
+                    .chars()
+                    .filter(|&item| left.contains(item) && right.contains(item))
+                    .map(naive_prio)
+                    .sum::<i64>()
+            })
+            .sum()
+    }
+
+    fn naive_part2(rucksacks: &[String]) -> i64 {
+        rucksacks
+            .chunks(3)
+            .map(|group| {
+                ITEMS
+                    .chars()
+                    .filter(|&item| group.iter().all(|rucksack| rucksack.contains(item)))
+                    .map(naive_prio)
+                    .sum::<i64>()
+            })
+            .sum()
+    }
+}
@@ -0,0 +1,148 @@
+use crate::{
+    input::Input,
+    parsing::{self, Line},
+    Answer, ParseError, Solution,
+};
+use eyre::Result;
+use std::ops::RangeInclusive;
+
+const DAY: u8 = 4;
+
+/// The sections assigned to a pair of elves.
+type Pair = (RangeInclusive<u64>, RangeInclusive<u64>);
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    type Parsed = Vec<Pair>;
+
+    fn parse(input: &Input<'_>) -> Result<Self::Parsed, ParseError> {
+        parse(input)
+    }
+
+    fn part1(ranges: &Self::Parsed) -> Result<Answer> {
+        Ok(part1(ranges).into())
+    }
+
+    fn part2(ranges: &Self::Parsed) -> Result<Answer> {
+        Ok(part2(ranges).into())
+    }
+}
+
+fn part1(ranges: &[Pair]) -> usize {
+    ranges
+        .iter()
+        .filter(|(first, second)| {
+            (first.start() >= second.start() && first.end() <= second.end())
+                || (second.start() >= first.start() && second.end() <= first.end())
+        })
+        .count()
+}
+
+fn part2(ranges: &[Pair]) -> usize {
+    ranges
+        .iter()
+        .filter(|(first, second)| {
+            (first.start() >= second.start() && first.start() <= second.end())
+                || (first.end() <= second.end() && first.end() >= second.start())
+                || (second.start() >= first.start() && second.start() <= first.end())
+                || (second.end() <= first.end() && second.end() >= first.start())
+        })
+        .count()
+}
+
+fn parse(input: &Input<'_>) -> Result<Vec<Pair>, ParseError> {
+    parsing::each_line(input, DAY, |line| {
+        let range = |line: &mut Line| {
+            let ((from, to), span) = line.spanned(|line| {
+                let from: u64 = line.integer("a number")?;
+                line.keyword("-")?;
+                Ok((from, line.integer("a number")?))
+            })?;
+            if from > to {
+                return Err(line.error(span, "a range that does not end before it starts"));
+            }
+            Ok(from..=to)
+        };
+
+        let first = range(line)?;
+        line.keyword(",")?;
+        Ok((first, range(line)?))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+    use proptest::prelude::*;
+
+    #[test]
+    fn parse_correctly() {
+        assert_eq!(
+            parse(&Input::new(include_str!(
+                "../../fixtures/day4/example1.txt"
+            )))
+            .unwrap(),
+            vec![
+                (2..=4, 6..=8),
+                (2..=3, 4..=5),
+                (5..=7, 7..=9),
+                (2..=8, 3..=7),
+                (6..=6, 4..=6),
+                (2..=6, 4..=8),
+            ],
+        );
+    }
+
+    #[test]
+    fn parse_reports_error() {
+        let err = parse(&Input::new("2-4,6-8\n2-3,4_5")).unwrap_err();
+        assert_eq!((err.line, err.column, err.found.as_str()), (2, 5, "4_5"));
+        let err = parse(&Input::new("2-4,8-6")).unwrap_err();
+        assert_eq!((err.line, err.column, err.found.as_str()), (1, 5, "8-6"));
+    }
+
+    proptest! {
+        #[test]
+        fn parse_round_trips(pairs in pairs()) {
+            prop_assert_eq!(parse(&Input::new(&print(&pairs))).unwrap(), pairs);
+        }
+
+        #[test]
+        fn parts_match_naive(pairs in pairs()) {
+            prop_assert_eq!(part1(&pairs), naive_part1(&pairs));
+            prop_assert_eq!(part2(&pairs), naive_part2(&pairs));
+        }
+    }
+
+    fn pairs() -> impl Strategy<Value = Vec<Pair>> {
+        let range = (0..100u64, 0..20u64).prop_map(|(start, len)| start..=start + len);
+        prop::collection::vec((range.clone(), range), 0..100)
+    }
+
+    fn print(pairs: &[Pair]) -> String {
+        pairs
+            .iter()
+            .map(|(a, b)| format!("{}-{},{}-{}\n", a.start(), a.end(), b.start(), b.end()))
+            .collect()
+    }
+
+    /// Checks section by section.
+    fn naive_part1(pairs: &[Pair]) -> usize {
+        pairs
+            .iter()
+            .filter(|(a, b)| {
+                a.clone().all(|section| b.contains(&section))
+                    || b.clone().all(|section| a.contains(&section))
+            })
+            .count()
+    }
+
+    fn naive_part2(pairs: &[Pair]) -> usize {
+        pairs
+            .iter()
+            .filter(|(a, b)| a.clone().any(|section| b.contains(&section)))
+            .count()
+    }
+}
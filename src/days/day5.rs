@@ -0,0 +1,346 @@
+use crate::{
+    input,
+    parsing::{self, Line},
+    Answer, ParseError, Solution,
+};
+use eyre::{eyre, Result};
+use itertools::Itertools as _;
+use std::collections::VecDeque;
+use tracing::debug;
+
+const DAY: u8 = 5;
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    type Parsed = Input;
+
+    fn parse(input: &input::Input<'_>) -> Result<Self::Parsed, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Parsed) -> Result<Answer> {
+        part1(input).map(Answer::from)
+    }
+
+    fn part2(input: &Self::Parsed) -> Result<Answer> {
+        part2(input).map(Answer::from)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Input {
+    stacks: VecDeque<VecDeque<char>>,
+    instructions: VecDeque<Move>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Move {
+    count: usize,
+    from: usize,
+    to: usize,
+}
+
+fn part1(input: &Input) -> Result<String> {
+    let mut input = input.clone();
+    for mv in input.instructions {
+        debug!(mv.count, mv.from, mv.to, "moving crates one at a time");
+        if mv.count > input.stacks[mv.from - 1].len() {
+            return Err(eyre!("for {mv:?}, could not perform move"));
+        }
+        // Moving crates onto the stack they came from leaves it as it was.
+        if mv.from == mv.to {
+            continue;
+        }
+        for _ in 0..mv.count {
+            let moving = input.stacks[mv.from - 1]
+                .pop_back()
+                .expect("there are enough crates to move");
+            input.stacks[mv.to - 1].push_back(moving);
+        }
+    }
+
+    Ok(input
+        .stacks
+        .into_iter()
+        .filter_map(|mut s| s.pop_back())
+        .join(""))
+}
+
+fn part2(input: &Input) -> Result<String> {
+    let mut input = input.clone();
+    for mv in input.instructions {
+        debug!(mv.count, mv.from, mv.to, "moving crates all at once");
+        if mv.count == 1 {
+            let moving = input.stacks[mv.from - 1]
+                .pop_back()
+                .ok_or_else(|| eyre!("for {mv:?}, could not perform move"))?;
+
+            input.stacks[mv.to - 1].push_back(moving);
+            continue;
+        }
+
+        let from = &mut input.stacks[mv.from - 1];
+        let at = from
+            .len()
+            .checked_sub(mv.count)
+            .ok_or_else(|| eyre!("for {mv:?}, could not perform move"))?;
+        let mut moving = from.split_off(at);
+        input.stacks[mv.to - 1].append(&mut moving);
+    }
+
+    Ok(input
+        .stacks
+        .into_iter()
+        .filter_map(|mut s| s.pop_back())
+        .join(""))
+}
+
+fn parse(input: &input::Input<'_>) -> Result<Input, ParseError> {
+    let mut groups = parsing::groups(input, DAY);
+    let mut drawing = groups.next().unwrap_or_default();
+    // Every other group holds moves, however many blank lines there are between them.
+    let moves = groups.flatten();
+    if let Some(first_move) = drawing
+        .iter()
+        .position(|line| line.rest().starts_with("move "))
+        .filter(|&first_move| first_move > 0)
+    {
+        let line = drawing[first_move - 1];
+        return Err(line.error(
+            &line.rest()[line.rest().len()..],
+            "a blank line between the crates and the moves",
+        ));
+    }
+
+    // The crates look like:
+    //
+    //     [D]
+    // [N] [C]
+    // [Z] [M] [P]
+    //  1   2   3
+    // This counts the numbers in the last line, which enumerates the graph.
+    let mut number_line = drawing
+        .pop()
+        .ok_or_else(|| ParseError::missing(DAY, 0, "", "a line of stack numbers"))?;
+    number_line.trim();
+    let numbers = number_line.separated(
+        |line| !line.spaces().is_empty(),
+        |line| line.spanned(|line| line.integer::<usize>("a stack number")),
+    )?;
+    for (idx, &(number, span)) in numbers.iter().enumerate() {
+        // The stacks are numbered in order, so there can't be more of them than fit on the line.
+        if number != idx + 1 {
+            return Err(number_line.error(span, format!("stack number {}", idx + 1)));
+        }
+    }
+    let columns = numbers.len();
+    let mut stacks = VecDeque::with_capacity(columns);
+    for _ in 0..columns {
+        stacks.push_back(VecDeque::new());
+    }
+    // Going bottom up, the line of integers we don't care about having been taken off already.
+    for line in drawing.iter().rev() {
+        // Each crate takes up four characters, like `[A] `, with its letter second. Stacks
+        // without a crate this high may have been trimmed off the end of the line.
+        let slots = line.rest().char_indices().skip(1).step_by(4);
+        for (idx, (byte_idx, ch)) in slots.enumerate() {
+            if ch == ' ' {
+                continue;
+            }
+            let found = &line.rest()[byte_idx..byte_idx + ch.len_utf8()];
+            match stacks.get_mut(idx) {
+                None => return Err(line.error(found, "a crate over a numbered stack")),
+                Some(stack) if ch.is_alphabetic() => stack.push_back(ch),
+                Some(_) => return Err(line.error(found, "a crate letter or a space")),
+            }
+        }
+    }
+
+    // The moves look like:
+    //
+    // move 1 from 2 to 1
+    // move 3 from 1 to 3
+    // move 2 from 2 to 1
+    // move 1 from 1 to 2
+    let stack = |line: &mut Line| {
+        let (number, span) = line.spanned(|line| line.integer("a stack number"))?;
+        if !(1..=columns).contains(&number) {
+            return Err(line.error(span, format!("a stack number from 1 to {columns}")));
+        }
+        Ok(number)
+    };
+    let mut instructions = VecDeque::new();
+    for mut line in moves {
+        line.keyword("move ")?;
+        let count = line.integer("a number of crates")?;
+        line.keyword(" from ")?;
+        let from = stack(&mut line)?;
+        line.keyword(" to ")?;
+        let to = stack(&mut line)?;
+        line.end()?;
+        instructions.push_back(Move { count, from, to });
+    }
+
+    Ok(Input {
+        stacks,
+        instructions,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gen;
+    use pretty_assertions::assert_eq;
+    use proptest::prelude::*;
+
+    #[test]
+    fn parse_correctly() {
+        assert_eq!(
+            parse(&input::Input::new(include_str!(
+                "../../fixtures/day5/example1.txt"
+            )))
+            .unwrap(),
+            Input {
+                stacks: deq([deq(['Z', 'N']), deq(['M', 'C', 'D']), deq(['P'])]),
+                instructions: deq([mv(1, 2, 1), mv(3, 1, 3), mv(2, 2, 1), mv(1, 1, 2)]),
+            }
+        );
+    }
+
+    #[test]
+    fn moves_only_crates_that_are_there() {
+        let input = parse(&input::Input::new("[A]\n 1 \n\nmove 2 from 1 to 1\n")).unwrap();
+        assert!(part1(&input).is_err());
+        assert!(part2(&input).is_err());
+        let input = parse(&input::Input::new("[A]\n 1 \n\nmove 1 from 1 to 1\n")).unwrap();
+        assert_eq!(part1(&input).unwrap(), "A");
+        assert_eq!(part2(&input).unwrap(), "A");
+    }
+
+    #[test]
+    fn parse_reports_error() {
+        let err = parse(&input::Input::new(
+            "[A]\n 1 \n\nmove 1 from 1 to 1\nmove 1 from 2 to 1",
+        ))
+        .unwrap_err();
+        assert_eq!((err.line, err.column, err.found.as_str()), (5, 13, "2"));
+        let err = parse(&input::Input::new("[A]\n 1 \nmove 1 from 1 to 1")).unwrap_err();
+        assert_eq!((err.line, err.column, err.found.as_str()), (2, 4, ""));
+        let err = parse(&input::Input::new("[A] [B]\n 1   3 \n\n")).unwrap_err();
+        assert_eq!((err.line, err.column, err.found.as_str()), (2, 6, "3"));
+        let err = parse(&input::Input::new("[A] [B]\n 1 \n\nmove 1 from 1 to 1")).unwrap_err();
+        assert_eq!((err.line, err.column, err.found.as_str()), (1, 6, "B"));
+    }
+
+    proptest! {
+        #[test]
+        fn parse_round_trips(input in input()) {
+            prop_assert_eq!(parse(&input::Input::new(&print(&input))).unwrap(), input);
+        }
+
+        #[test]
+        fn parts_match_naive(input in input()) {
+            prop_assert_eq!(part1(&input).unwrap(), naive_part1(&input));
+            prop_assert_eq!(part2(&input).unwrap(), naive_part2(&input));
+        }
+    }
+
+    fn mv(count: usize, from: usize, to: usize) -> Move {
+        Move { count, from, to }
+    }
+
+    fn deq<const N: usize, T>(elem: [T; N]) -> VecDeque<T> {
+        let mut deque = VecDeque::with_capacity(N);
+        for e in elem {
+            deque.push_back(e);
+        }
+        deque
+    }
+
+    /// Moves that only ever take crates that are there.
+    fn input() -> impl Strategy<Value = Input> {
+        let stack = prop::collection::vec(prop::char::range('A', 'Z'), 0..8);
+        (
+            prop::collection::vec(stack, 1..20),
+            prop::collection::vec(any::<(usize, usize, usize)>(), 0..30),
+        )
+            .prop_map(|(stacks, picks)| {
+                let mut heights = stacks.iter().map(Vec::len).collect_vec();
+                let mut instructions = VecDeque::new();
+                for (from, count, to) in picks {
+                    let nonempty = (0..heights.len()).filter(|&i| heights[i] > 0).collect_vec();
+                    if nonempty.is_empty() {
+                        break;
+                    }
+                    let from = nonempty[from % nonempty.len()];
+                    let count = 1 + count % heights[from];
+                    let to = to % heights.len();
+                    heights[from] -= count;
+                    heights[to] += count;
+                    instructions.push_back(mv(count, from + 1, to + 1));
+                }
+                Input {
+                    stacks: stacks.into_iter().map(VecDeque::from).collect(),
+                    instructions,
+                }
+            })
+    }
+
+    fn print(input: &Input) -> String {
+        let stacks = input
+            .stacks
+            .iter()
+            .map(|s| s.iter().copied().collect_vec())
+            .collect_vec();
+        let moves = input
+            .instructions
+            .iter()
+            .map(|mv| (mv.count, mv.from, mv.to))
+            .collect_vec();
+        gen::day5::print(&stacks, &moves)
+    }
+
+    fn tops(stacks: Vec<Vec<char>>) -> String {
+        stacks
+            .into_iter()
+            .filter_map(|stack| stack.last().copied())
+            .collect()
+    }
+
+    fn naive_part1(input: &Input) -> String {
+        let mut stacks = input
+            .stacks
+            .iter()
+            .map(|s| s.iter().copied().collect_vec())
+            .collect_vec();
+        for mv in &input.instructions {
+            for _ in 0..mv.count {
+                let c = stacks[mv.from - 1].pop().unwrap();
+                stacks[mv.to - 1].push(c);
+            }
+        }
+        tops(stacks)
+    }
+
+    /// Moves the crates one by one onto a pile on the side, then one by one from there, which keeps
+    /// them in order.
+    fn naive_part2(input: &Input) -> String {
+        let mut stacks = input
+            .stacks
+            .iter()
+            .map(|s| s.iter().copied().collect_vec())
+            .collect_vec();
+        for mv in &input.instructions {
+            let mut side = Vec::new();
+            for _ in 0..mv.count {
+                side.push(stacks[mv.from - 1].pop().unwrap());
+            }
+            while let Some(c) = side.pop() {
+                stacks[mv.to - 1].push(c);
+            }
+        }
+        tops(stacks)
+    }
+}
@@ -0,0 +1,116 @@
+use crate::{
+    input::Input,
+    parsing::{self, Line},
+    Answer, ParseError, Solution,
+};
+use eyre::{eyre, Result};
+use itertools::Itertools as _;
+use tracing::debug;
+
+const DAY: u8 = 6;
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    type Parsed = String;
+
+    fn parse(input: &Input<'_>) -> Result<Self::Parsed, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Parsed) -> Result<Answer> {
+        part1(input).map(Answer::from)
+    }
+
+    fn part2(input: &Self::Parsed) -> Result<Answer> {
+        part2(input).map(Answer::from)
+    }
+}
+
+fn part1(input: &str) -> Result<usize> {
+    find_marker(input, 4).ok_or_else(|| eyre!("no start of packet in: {input}"))
+}
+
+fn part2(input: &str) -> Result<usize> {
+    find_marker(input, 14).ok_or_else(|| eyre!("no start of message in: {input}"))
+}
+
+/// Finds where the first `len` different characters in a row end.
+fn find_marker(input: &str, len: usize) -> Option<usize> {
+    // The input is all ASCII, so bytes are characters.
+    input
+        .as_bytes()
+        .windows(len)
+        .position(|window| {
+            let unique = window.iter().unique().count();
+            debug!(window = %String::from_utf8_lossy(window), unique, "checking window");
+            unique == len
+        })
+        .map(|start| start + len)
+}
+
+fn parse(input: &Input<'_>) -> Result<String, ParseError> {
+    let mut lines = parsing::lines(input, DAY);
+    let mut line = lines.next().unwrap_or(Line::new(DAY, 0, ""));
+    line.trim();
+    let signal = line.take_while(|c| c.is_ascii_lowercase());
+    if !line.is_empty() {
+        return Err(line.expected("a letter from `a` to `z`"));
+    }
+    if let Some(line) = lines.find(|line| !line.rest().trim().is_empty()) {
+        return Err(line.error(line.rest(), "the end of the signal"));
+    }
+    Ok(signal.to_owned())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+    use proptest::prelude::*;
+
+    #[test]
+    fn parse_reports_error() {
+        let err = parse(&Input::new("abcdé")).unwrap_err();
+        assert_eq!((err.line, err.column, err.found.as_str()), (1, 5, "é"));
+        let err = parse(&Input::new("abcd\nefgh\n")).unwrap_err();
+        assert_eq!((err.line, err.column, err.found.as_str()), (2, 1, "efgh"));
+    }
+
+    proptest! {
+        #[test]
+        fn parse_round_trips(signal in signal()) {
+            prop_assert_eq!(parse(&Input::new(&signal)).unwrap(), signal);
+        }
+
+        #[test]
+        fn parts_match_naive(signal in signal()) {
+            prop_assert_eq!(part1(&signal).unwrap(), naive_marker(&signal, 4));
+            prop_assert_eq!(part2(&signal).unwrap(), naive_marker(&signal, 14));
+        }
+    }
+
+    /// Random letters with fourteen different ones somewhere in the middle, so both parts have an
+    /// answer.
+    fn signal() -> impl Strategy<Value = String> {
+        (
+            "[a-z]{0,50}",
+            Just(('a'..='z').collect::<Vec<_>>()).prop_shuffle(),
+            "[a-z]{1,50}",
+        )
+            .prop_map(|(before, letters, after)| {
+                before + &letters[..14].iter().collect::<String>() + &after
+            })
+    }
+
+    /// Compares every pair of characters in every window.
+    fn naive_marker(signal: &str, len: usize) -> usize {
+        let chars: Vec<char> = signal.chars().collect();
+        (len..=chars.len())
+            .find(|&end| {
+                let window = &chars[end - len..end];
+                (0..len).all(|i| (i + 1..len).all(|j| window[i] != window[j]))
+            })
+            .unwrap()
+    }
+}
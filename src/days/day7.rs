@@ -0,0 +1,322 @@
+use crate::{input::Input, parsing, Answer, ParseError, Solution};
+use eyre::{eyre, Result};
+use std::{
+    cmp::Reverse,
+    collections::{HashMap, HashSet},
+    path::PathBuf,
+};
+use tracing::debug;
+
+const DAY: u8 = 7;
+/// How deep directories can nest. Real inputs stay well within it, and each directory keeps its
+/// whole path, so hostile ones would otherwise take memory quadratic in how deep they go.
+const MAX_DEPTH: usize = 1_000;
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    type Parsed = FileSystem;
+
+    fn parse(input: &Input<'_>) -> Result<Self::Parsed, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Parsed) -> Result<Answer> {
+        Ok(part1(input).into())
+    }
+
+    fn part2(input: &Self::Parsed) -> Result<Answer> {
+        part2(input).map(Answer::from)
+    }
+}
+
+fn part1(input: &FileSystem) -> u64 {
+    input
+        .dirs
+        .keys()
+        .map(|path| input.path_sizes.get(path).copied().unwrap_or(0))
+        .filter(|&it| it <= 100_000)
+        .sum()
+}
+
+fn part2(input: &FileSystem) -> Result<u64> {
+    const TOTAL_SPACE: u64 = 70_000_000;
+    const MIN_SPACE: u64 = 30_000_000;
+
+    let currently_used = input
+        .path_sizes
+        .get(&PathBuf::from("/"))
+        .copied()
+        .ok_or_else(|| eyre!("expecting size of /"))?;
+    let unused = TOTAL_SPACE
+        .checked_sub(currently_used)
+        .ok_or_else(|| eyre!("{currently_used} is used, but the disk only has {TOTAL_SPACE}"))?;
+    let to_free = MIN_SPACE
+        .checked_sub(unused)
+        .ok_or_else(|| eyre!("{unused} is unused, so there is no need to free anything"))?;
+
+    input
+        .dirs
+        .keys()
+        .filter_map(|path| {
+            let size = input.path_sizes.get(path).copied().unwrap_or(0);
+            let enough = size >= to_free;
+            if enough {
+                debug!(path = %path.display(), size, "big enough to free");
+                Some(size)
+            } else {
+                None
+            }
+        })
+        .min()
+        .ok_or_else(|| eyre!("expecting a minimum size dir"))
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct FileSystem {
+    // subdirs/files are not in a Vec, because each dir can only contain unique elements
+    // that one was annoying to figure out...
+    dirs: HashMap<PathBuf, HashSet<PathBuf>>,
+    files: HashMap<PathBuf, u64>,
+    path_sizes: HashMap<PathBuf, u64>,
+}
+
+fn parse(input: &Input<'_>) -> Result<FileSystem, ParseError> {
+    let mut dirs = HashMap::new();
+    let mut files = HashMap::new();
+    // Start at root at all times.
+    let mut pwd = PathBuf::from("/");
+    // Lines that aren't commands are only valid as the output of an `ls`.
+    let mut listing = false;
+    // The size of every file seen so far, which no directory can be bigger than.
+    let mut total: u64 = 0;
+
+    for (idx, mut line) in parsing::lines(input, DAY).enumerate() {
+        if line.eat("$ ") {
+            listing = false;
+            if line.eat("cd ") {
+                let path = line.take_rest();
+                debug!(line = idx + 1, from = %pwd.display(), to = path, "cd");
+                if path == "/" {
+                    pwd = PathBuf::from("/");
+                } else if path == ".." {
+                    let _ = pwd.pop();
+                } else {
+                    // A real path!
+                    check_name(path).map_err(|expected| line.error(path, expected))?;
+                    // The root is a component of its own.
+                    if pwd.components().count() > MAX_DEPTH {
+                        let expected =
+                            format!("`cd ..`, as directories nest {MAX_DEPTH} deep at most");
+                        return Err(line.error(path, expected));
+                    }
+                    let mut file_path = pwd.clone();
+                    file_path.push(path);
+                    dirs.entry(pwd.clone())
+                        .or_insert_with(|| HashSet::with_capacity(1))
+                        .insert(file_path);
+                    pwd.push(path); // don't add an entry in case nothing is there
+                }
+            } else if line.rest() == "ls" {
+                listing = true;
+            } else {
+                return Err(line.error(line.rest(), "`cd <dir>` or `ls`"));
+            }
+        } else if listing {
+            let size = if line.eat("dir ") {
+                None
+            } else {
+                let size = line.spanned(|line| line.integer::<u64>("a file size or `dir`"))?;
+                line.keyword(" ")?;
+                Some(size)
+            };
+            let path = line.take_rest();
+            check_name(path).map_err(|expected| line.error(path, expected))?;
+            let mut file_path = pwd.clone();
+            file_path.push(path);
+            dirs.entry(pwd.clone())
+                .or_insert_with(|| HashSet::with_capacity(1))
+                .insert(file_path.clone());
+
+            let Some((size, span)) = size else {
+                continue;
+            };
+            total = total
+                .checked_add(size)
+                .ok_or_else(|| line.error(span, "a file size that fits on a disk"))?;
+            files.insert(file_path, size);
+        } else if !line.rest().trim().is_empty() {
+            return Err(line.error(line.rest(), "a command starting with `$ `"));
+        }
+    }
+
+    let path_sizes = dir_sizes(&dirs, &files);
+    Ok(FileSystem {
+        dirs,
+        files,
+        path_sizes,
+    })
+}
+
+/// The size of every directory, including empty ones and the root.
+///
+/// Directories are sized deepest first, so that the sizes of their subdirectories are always
+/// known by then, rather than by recursing into them, which could overflow the stack.
+fn dir_sizes(
+    dirs: &HashMap<PathBuf, HashSet<PathBuf>>,
+    files: &HashMap<PathBuf, u64>,
+) -> HashMap<PathBuf, u64> {
+    let mut paths: Vec<&PathBuf> = dirs.keys().collect();
+    paths.sort_by_key(|path| Reverse(path.components().count()));
+
+    let mut sizes = HashMap::with_capacity(dirs.len() + 1);
+    sizes.insert(PathBuf::from("/"), 0);
+    for path in paths {
+        let mut size = 0;
+        for entry in &dirs[path] {
+            size += match files.get(entry) {
+                Some(&file_size) => file_size,
+                // It's a directory, which is empty unless it was sized already.
+                None => *sizes.entry(entry.clone()).or_insert(0),
+            };
+        }
+        sizes.insert(path.clone(), size);
+    }
+    sizes
+}
+
+/// Checks that a name is of a single entry in the current directory, so following names can
+/// never loop back around. Returns what was expected if it isn't.
+fn check_name(name: &str) -> Result<(), &'static str> {
+    if name.is_empty() || name == "." || name == ".." || name.contains('/') {
+        return Err("the name of an entry in the current directory");
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gen::day7::{print, Node};
+    use pretty_assertions::assert_eq;
+    use proptest::prelude::*;
+    use std::collections::BTreeMap;
+
+    #[test]
+    fn parse_reports_error() {
+        let err = parse(&Input::new("$ cd /\n$ ls\n12 a.txt\n$ rm a.txt")).unwrap_err();
+        assert_eq!(
+            (err.line, err.column, err.found.as_str()),
+            (4, 3, "rm a.txt")
+        );
+        let err = parse(&Input::new("$ ls\ntwelve a.txt")).unwrap_err();
+        assert_eq!((err.line, err.column, err.found.as_str()), (2, 1, "twelve"));
+        let err = parse(&Input::new("$ ls\ndir a/b")).unwrap_err();
+        assert_eq!((err.line, err.column, err.found.as_str()), (2, 5, "a/b"));
+    }
+
+    #[test]
+    fn sizes_deeply_nested_directories() {
+        let nested = |depth| format!("{}$ ls\n12 f\n", "$ cd a\n".repeat(depth));
+        let fs = parse(&Input::new(&nested(MAX_DEPTH))).unwrap();
+        assert_eq!(fs.path_sizes.len(), MAX_DEPTH + 1);
+        assert!(fs.path_sizes.values().all(|&size| size == 12));
+
+        let err = parse(&Input::new(&nested(MAX_DEPTH + 1))).unwrap_err();
+        assert_eq!((err.line, err.found.as_str()), (MAX_DEPTH + 1, "a"));
+    }
+
+    proptest! {
+        #[test]
+        fn parse_round_trips(root in tree()) {
+            let fs = file_system(&root);
+            prop_assert_eq!(parse(&Input::new(&print(&root))).unwrap(), fs);
+        }
+
+        #[test]
+        fn parts_match_naive(root in tree()) {
+            let fs = file_system(&root);
+            prop_assert_eq!(part1(&fs), naive_part1(&root));
+            prop_assert_eq!(part2(&fs).unwrap(), naive_part2(&root));
+        }
+    }
+
+    /// The entries in `/`, which use between 40M and 70M of space, so there is a disk to fill
+    /// but also something to free for part 2.
+    fn tree() -> impl Strategy<Value = BTreeMap<String, Node>> {
+        const NAME: &str = "[a-z]{1,8}";
+        let node = (1..200_000u64)
+            .prop_map(Node::File)
+            .prop_recursive(4, 64, 6, |inner| {
+                prop::collection::btree_map(NAME, inner, 0..6).prop_map(Node::Dir)
+            });
+        (
+            prop::collection::btree_map(NAME, node, 0..8),
+            40_000_001..=70_000_000u64,
+        )
+            .prop_map(|(mut root, used)| {
+                let mut sizes = Vec::new();
+                let size = dir_sizes(&root, &mut sizes);
+                // The names never have a dot, so this can't replace anything.
+                root.insert("big.dat".to_owned(), Node::File(used - size));
+                root
+            })
+    }
+
+    fn file_system(root: &BTreeMap<String, Node>) -> FileSystem {
+        fn add(fs: &mut FileSystem, path: PathBuf, entries: &BTreeMap<String, Node>) -> u64 {
+            let mut size = 0;
+            for (name, node) in entries {
+                let entry = path.join(name);
+                fs.dirs
+                    .entry(path.clone())
+                    .or_default()
+                    .insert(entry.clone());
+                size += match node {
+                    Node::File(file_size) => {
+                        fs.files.insert(entry, *file_size);
+                        *file_size
+                    }
+                    Node::Dir(entries) => add(fs, entry, entries),
+                };
+            }
+            fs.path_sizes.insert(path, size);
+            size
+        }
+
+        let mut fs = FileSystem {
+            dirs: HashMap::new(),
+            files: HashMap::new(),
+            path_sizes: HashMap::new(),
+        };
+        add(&mut fs, PathBuf::from("/"), root);
+        fs
+    }
+
+    /// Adds the size of every directory in `entries` to `sizes`, and returns their own total.
+    fn dir_sizes(entries: &BTreeMap<String, Node>, sizes: &mut Vec<u64>) -> u64 {
+        let size = entries
+            .values()
+            .map(|node| match node {
+                Node::File(size) => *size,
+                Node::Dir(entries) => dir_sizes(entries, sizes),
+            })
+            .sum();
+        sizes.push(size);
+        size
+    }
+
+    fn naive_part1(root: &BTreeMap<String, Node>) -> u64 {
+        let mut sizes = Vec::new();
+        dir_sizes(root, &mut sizes);
+        sizes.into_iter().filter(|&size| size <= 100_000).sum()
+    }
+
+    fn naive_part2(root: &BTreeMap<String, Node>) -> u64 {
+        let mut sizes = Vec::new();
+        let used = dir_sizes(root, &mut sizes);
+        let to_free = used - 40_000_000;
+        sizes.sort();
+        sizes.into_iter().find(|&size| size >= to_free).unwrap()
+    }
+}
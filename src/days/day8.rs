@@ -0,0 +1,177 @@
+use crate::{input::Input, parsing, Answer, ParseError, Solution};
+use eyre::Result;
+use itertools::Itertools as _;
+
+const DAY: u8 = 8;
+
+type TreeHeight = usize;
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    type Parsed = Vec<Vec<TreeHeight>>;
+
+    fn parse(input: &Input<'_>) -> Result<Self::Parsed, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Parsed) -> Result<Answer> {
+        Ok(part1(input).into())
+    }
+
+    fn part2(input: &Self::Parsed) -> Result<Answer> {
+        Ok(part2(input).into())
+    }
+}
+
+fn part1(input: &[Vec<TreeHeight>]) -> usize {
+    let mut visible = vec![vec![false; input[0].len()]; input.len()];
+    for line in lines_of_sight(input) {
+        // A tree can be seen from the edge if it is taller than every tree before it.
+        let mut tallest = None;
+        for (row, column) in line {
+            let height = input[row][column];
+            if tallest.is_none_or(|tallest| height > tallest) {
+                visible[row][column] = true;
+                tallest = Some(height);
+            }
+        }
+    }
+    visible.iter().flatten().filter(|&&visible| visible).count()
+}
+
+fn part2(input: &[Vec<TreeHeight>]) -> usize {
+    let mut scores = vec![vec![1; input[0].len()]; input.len()];
+    for line in lines_of_sight(input) {
+        // The trees that could still block the view of those further along: each one is
+        // shorter than the one before it, as a taller tree hides any shorter ones behind it.
+        let mut blockers: Vec<(usize, TreeHeight)> = Vec::new();
+        for (idx, (row, column)) in line.into_iter().enumerate() {
+            let height = input[row][column];
+            while blockers
+                .last()
+                .is_some_and(|&(_, blocker)| blocker < height)
+            {
+                blockers.pop();
+            }
+            // Looking back towards the edge, up to the first tree at least as tall.
+            scores[row][column] *= idx - blockers.last().map_or(0, |&(blocker, _)| blocker);
+            blockers.push((idx, height));
+        }
+    }
+    scores.into_iter().flatten().max().unwrap_or(0)
+}
+
+/// The positions of the trees along every row and column, in both directions: from the left,
+/// right, top and bottom edges.
+fn lines_of_sight(input: &[Vec<TreeHeight>]) -> impl Iterator<Item = Vec<(usize, usize)>> {
+    let (height, width) = (input.len(), input[0].len());
+    let rows = (0..height).map(move |row| (0..width).map(|column| (row, column)).collect_vec());
+    let columns = (0..width).map(move |column| (0..height).map(|row| (row, column)).collect_vec());
+    rows.chain(columns)
+        .flat_map(|line| [line.iter().rev().copied().collect(), line])
+}
+
+fn parse(input: &Input<'_>) -> Result<Vec<Vec<TreeHeight>>, ParseError> {
+    parsing::digit_grid(input, DAY, "a tree height from 0 to 9")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+    use proptest::prelude::*;
+
+    fn input() -> Vec<Vec<TreeHeight>> {
+        vec![
+            vec![3, 0, 3, 7, 3],
+            vec![2, 5, 5, 1, 2],
+            vec![6, 5, 3, 3, 2],
+            vec![3, 3, 5, 4, 9],
+            vec![3, 5, 3, 9, 0],
+        ]
+    }
+
+    #[test]
+    fn parse_correct() {
+        assert_eq!(
+            parse(&Input::new(include_str!(
+                "../../fixtures/day8/example1.txt"
+            )))
+            .unwrap(),
+            input()
+        );
+    }
+
+    #[test]
+    fn parse_reports_error() {
+        let err = parse(&Input::new("303\n2x5\n653")).unwrap_err();
+        assert_eq!((err.line, err.column, err.found.as_str()), (2, 2, "x"));
+        let err = parse(&Input::new("303\n25\n653")).unwrap_err();
+        assert_eq!((err.line, err.column, err.found.as_str()), (2, 1, "25"));
+        let err = parse(&Input::new("\n")).unwrap_err();
+        assert_eq!((err.line, err.column, err.found.as_str()), (1, 1, ""));
+    }
+
+    proptest! {
+        #[test]
+        fn parse_round_trips(grid in grid()) {
+            prop_assert_eq!(parse(&Input::new(&print(&grid))).unwrap(), grid);
+        }
+
+        #[test]
+        fn parts_match_naive(grid in grid()) {
+            prop_assert_eq!(part1(&grid), naive_part1(&grid));
+            prop_assert_eq!(part2(&grid), naive_part2(&grid));
+        }
+    }
+
+    fn grid() -> impl Strategy<Value = Vec<Vec<TreeHeight>>> {
+        (1..12usize, 1..12usize).prop_flat_map(|(rows, columns)| {
+            prop::collection::vec(prop::collection::vec(0..10usize, columns), rows)
+        })
+    }
+
+    fn print(grid: &[Vec<TreeHeight>]) -> String {
+        grid.iter().map(|row| row.iter().join("") + "\n").collect()
+    }
+
+    /// How far you can see from a tree in each direction, up, down, left and right.
+    fn views(grid: &[Vec<TreeHeight>], row: usize, column: usize) -> [Vec<TreeHeight>; 4] {
+        let height = grid.len();
+        let width = grid[0].len();
+        [
+            (0..row).rev().map(|r| grid[r][column]).collect(),
+            (row + 1..height).map(|r| grid[r][column]).collect(),
+            (0..column).rev().map(|c| grid[row][c]).collect(),
+            (column + 1..width).map(|c| grid[row][c]).collect(),
+        ]
+    }
+
+    fn naive_part1(grid: &[Vec<TreeHeight>]) -> usize {
+        (0..grid.len())
+            .cartesian_product(0..grid[0].len())
+            .filter(|&(r, c)| {
+                views(grid, r, c)
+                    .iter()
+                    .any(|view| view.iter().all(|&h| h < grid[r][c]))
+            })
+            .count()
+    }
+
+    fn naive_part2(grid: &[Vec<TreeHeight>]) -> usize {
+        (0..grid.len())
+            .cartesian_product(0..grid[0].len())
+            .map(|(r, c)| {
+                views(grid, r, c)
+                    .iter()
+                    .map(|view| match view.iter().position(|&h| h >= grid[r][c]) {
+                        Some(blocked) => blocked + 1,
+                        None => view.len(),
+                    })
+                    .product()
+            })
+            .max()
+            .unwrap()
+    }
+}
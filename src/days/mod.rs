@@ -0,0 +1,229 @@
+use crate::{input::Input, Answer, Solution};
+use color_eyre::{Section as _, SectionExt as _};
+use eyre::{eyre, Result};
+use std::{any::Any, fmt, str::FromStr};
+use tracing::info_span;
+
+pub mod day1;
+pub mod day2;
+pub mod day3;
+pub mod day4;
+pub mod day5;
+pub mod day6;
+pub mod day7;
+pub mod day8;
+
+/// The puzzle input for a day, if it is to be compiled into the binary.
+#[cfg(feature = "embed-inputs")]
+macro_rules! embedded {
+    ($day:literal) => {
+        Some(include_str!(concat!("../../inputs/day", $day, ".txt")))
+    };
+}
+
+#[cfg(not(feature = "embed-inputs"))]
+macro_rules! embedded {
+    ($day:literal) => {
+        None
+    };
+}
+
+/// Every implemented day, in order.
+pub const DAYS: &[Day] = &[
+    // Counted in u128, so made up inputs with totals too big for a u64 still have answers.
+    Day::new::<day1::Day1<u128>>(1, embedded!(1)),
+    Day::new::<day2::Day2>(2, embedded!(2)),
+    Day::new::<day3::Day3>(3, embedded!(3)),
+    Day::new::<day4::Day4>(4, embedded!(4)),
+    Day::new::<day5::Day5>(5, embedded!(5)),
+    Day::new::<day6::Day6>(6, embedded!(6)),
+    Day::new::<day7::Day7>(7, embedded!(7)),
+    Day::new::<day8::Day8>(8, embedded!(8)),
+];
+
+/// Finds a day by its number.
+pub fn get(number: u8) -> Option<&'static Day> {
+    DAYS.iter().find(|day| day.number == number)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const BOTH: [Part; 2] = [Part::One, Part::Two];
+
+    pub fn number(self) -> u8 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.number())
+    }
+}
+
+impl FromStr for Part {
+    type Err = eyre::Report;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "1" => Ok(Part::One),
+            "2" => Ok(Part::Two),
+            _ => Err(eyre!("part must be 1 or 2, not {s:?}")),
+        }
+    }
+}
+
+/// A type-erased [`Solution`], so a day can be picked at runtime.
+///
+/// The parsed input is passed around as a `dyn Any`, and is only ever downcast back into the
+/// `Parsed` type of the solution that produced it.
+pub struct Day {
+    pub number: u8,
+    /// The input compiled in with the `embed-inputs` feature.
+    pub embedded_input: Option<&'static str>,
+    parse: fn(&str) -> Result<Box<dyn Any>>,
+    part1: fn(&dyn Any) -> Result<Answer>,
+    part2: fn(&dyn Any) -> Result<Answer>,
+}
+
+impl Day {
+    const fn new<S: Solution>(number: u8, embedded_input: Option<&'static str>) -> Self
+    where
+        S::Parsed: 'static,
+    {
+        Day {
+            number,
+            embedded_input,
+            parse: |input| match S::parse(&Input::new(input)) {
+                Ok(parsed) => Ok(Box::new(parsed)),
+                Err(err) => {
+                    let snippet = err.snippet();
+                    Err(eyre::Report::new(err).section(snippet.header("Input:")))
+                }
+            },
+            part1: |parsed| S::part1(downcast::<S>(parsed)),
+            part2: |parsed| S::part2(downcast::<S>(parsed)),
+        }
+    }
+
+    pub fn parse(&self, input: &str) -> Result<Box<dyn Any>> {
+        let _span = info_span!("parse", day = self.number, bytes = input.len()).entered();
+        (self.parse)(input)
+    }
+
+    /// Solves a part from input previously returned by this day's [`Day::parse`].
+    pub fn solve(&self, parsed: &dyn Any, part: Part) -> Result<Answer> {
+        match part {
+            Part::One => {
+                let _span = info_span!("part1", day = self.number).entered();
+                (self.part1)(parsed)
+            }
+            Part::Two => {
+                let _span = info_span!("part2", day = self.number).entered();
+                (self.part2)(parsed)
+            }
+        }
+    }
+
+    /// Parses the input and solves a single part from it.
+    pub fn run(&self, input: &str, part: Part) -> Result<Answer> {
+        self.solve(&*self.parse(input)?, part)
+    }
+
+    /// Feeds arbitrary bytes through the parser and both parts, for fuzzing. Whatever they are,
+    /// this should only ever end in an error, never a panic.
+    pub fn fuzz(&self, data: &[u8]) {
+        let input = String::from_utf8_lossy(data);
+        if let Ok(parsed) = self.parse(&input) {
+            for part in Part::BOTH {
+                let _ = self.solve(&*parsed, part);
+            }
+        }
+    }
+}
+
+impl fmt::Debug for Day {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Day").field("number", &self.number).finish()
+    }
+}
+
+fn downcast<S: Solution>(parsed: &dyn Any) -> &S::Parsed
+where
+    S::Parsed: 'static,
+{
+    parsed
+        .downcast_ref()
+        .expect("parsed input was not produced by the same day")
+}
+
+/// Which days to act on, as given on the command line: either a day number or `all`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Selection {
+    All,
+    Day(u8),
+}
+
+impl Selection {
+    pub fn days(self) -> Result<Vec<&'static Day>> {
+        match self {
+            Selection::All => Ok(DAYS.iter().collect()),
+            Selection::Day(number) => get(number)
+                .map(|day| vec![day])
+                .ok_or_else(|| eyre!("day {number} is not implemented")),
+        }
+    }
+}
+
+impl FromStr for Selection {
+    type Err = eyre::Report;
+
+    fn from_str(s: &str) -> Result<Self> {
+        if s == "all" {
+            return Ok(Selection::All);
+        }
+        s.parse()
+            .map(Selection::Day)
+            .map_err(|_| eyre!("expected a day number or `all`, not {s:?}"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn days_are_numbered_in_order() {
+        // Days can be skipped, but never repeated or out of order.
+        assert!(DAYS.windows(2).all(|pair| pair[0].number < pair[1].number));
+        assert!(DAYS.iter().all(|day| (1..=25).contains(&day.number)));
+    }
+
+    #[test]
+    fn selection_parses() {
+        assert_eq!("all".parse::<Selection>().unwrap(), Selection::All);
+        assert_eq!("5".parse::<Selection>().unwrap(), Selection::Day(5));
+        assert!("five".parse::<Selection>().is_err());
+        assert!(Selection::Day(26).days().is_err());
+    }
+
+    #[test]
+    fn runs_through_registry() {
+        assert_eq!(
+            get(6)
+                .unwrap()
+                .run("mjqjpqmgbljsphdztnvjfqwrcgsmlb", Part::Two)
+                .unwrap(),
+            Answer::from(19u64),
+        );
+    }
+}
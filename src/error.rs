@@ -0,0 +1,120 @@
+use std::{error::Error, fmt};
+
+/// Why a day's puzzle input could not be parsed, and where.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub day: u8,
+    /// 1-based line number.
+    pub line: usize,
+    /// 1-based column, counted in characters.
+    pub column: usize,
+    /// The entire line the error is on, for context.
+    pub source_line: String,
+    /// The offending text. Empty if something was missing rather than wrong.
+    pub found: String,
+    /// What should have been there instead.
+    pub expected: String,
+}
+
+impl ParseError {
+    /// Creates an error for `found` on the 0-based line `line_idx`, whose text is `line`.
+    ///
+    /// `found` should be a subslice of `line`, so its column can be worked out. If it isn't, the
+    /// error points at the start of the line.
+    pub fn new(
+        day: u8,
+        line_idx: usize,
+        line: &str,
+        found: &str,
+        expected: impl Into<String>,
+    ) -> Self {
+        let offset = (found.as_ptr() as usize)
+            .checked_sub(line.as_ptr() as usize)
+            .filter(|&offset| offset + found.len() <= line.len())
+            .unwrap_or(0);
+        ParseError {
+            day,
+            line: line_idx + 1,
+            column: line[..offset].chars().count() + 1,
+            source_line: line.to_owned(),
+            found: found.to_owned(),
+            expected: expected.into(),
+        }
+    }
+
+    /// Creates an error for something missing at the end of the 0-based line `line_idx`.
+    pub fn missing(day: u8, line_idx: usize, line: &str, expected: impl Into<String>) -> Self {
+        ParseError::new(day, line_idx, line, &line[line.len()..], expected)
+    }
+
+    /// Renders the offending line with the error underlined, like:
+    ///
+    /// ```text
+    /// 3 | 5-7,7+9
+    ///   |      ^^^
+    /// ```
+    pub fn snippet(&self) -> String {
+        let gutter = self.line.to_string().len();
+        format!(
+            "{line:>gutter$} | {source}\n{blank:gutter$} | {pad}{carets}",
+            line = self.line,
+            source = self.source_line,
+            blank = "",
+            pad = " ".repeat(self.column - 1),
+            carets = "^".repeat(self.found.chars().count().max(1)),
+        )
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "day {}, line {}, column {}: expected {}, ",
+            self.day, self.line, self.column, self.expected
+        )?;
+        if self.found.is_empty() {
+            f.write_str("found nothing")
+        } else {
+            write!(f, "found {:?}", self.found)
+        }
+    }
+}
+
+impl Error for ParseError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn points_at_found_text() {
+        let line = "5-7,7+9";
+        let err = ParseError::new(4, 2, line, &line[4..], "a `-` between the bounds");
+        assert_eq!((err.line, err.column), (3, 5));
+        assert_eq!(err.snippet(), "3 | 5-7,7+9\n  |     ^^^");
+        assert_eq!(
+            err.to_string(),
+            r#"day 4, line 3, column 5: expected a `-` between the bounds, found "7+9""#,
+        );
+    }
+
+    #[test]
+    fn points_past_end_when_missing() {
+        let err = ParseError::missing(2, 9, "A", "a space");
+        assert_eq!((err.line, err.column), (10, 2));
+        assert_eq!(err.snippet(), "10 | A\n   |  ^");
+        assert_eq!(
+            err.to_string(),
+            "day 2, line 10, column 2: expected a space, found nothing"
+        );
+    }
+
+    #[test]
+    fn counts_columns_in_chars() {
+        let line = "ææ x";
+        let err = ParseError::new(1, 0, line, &line[5..], "a number");
+        assert_eq!(err.column, 4);
+    }
+}
@@ -0,0 +1,143 @@
+use crate::days::Part;
+use eyre::{Result, WrapErr as _};
+use serde::Deserialize;
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+/// Where the example inputs live, relative to the current directory, which `aoc` and the tests
+/// expect to be the crate root.
+pub const FIXTURES_DIR: &str = "fixtures";
+
+/// An example input, like the ones in the puzzle text, and what a solution should make of it.
+///
+/// Each is a pair of files in `fixtures/dayN/`: the input in `<name>.txt`, and its expected answers
+/// in `<name>.toml`:
+///
+/// ```toml
+/// part1 = "24000"
+/// part2 = "45000"
+/// ```
+///
+/// Inputs that should be rejected give a part of the expected error instead, as `parse_error`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Fixture {
+    pub name: String,
+    pub input: String,
+    pub expected: Expected,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Expected {
+    pub part1: Option<String>,
+    pub part2: Option<String>,
+    pub parse_error: Option<String>,
+}
+
+impl Expected {
+    pub fn get(&self, part: Part) -> Option<&str> {
+        match part {
+            Part::One => self.part1.as_deref(),
+            Part::Two => self.part2.as_deref(),
+        }
+    }
+}
+
+/// The directory holding a day's fixtures.
+pub fn day_dir(dir: &Path, day: u8) -> PathBuf {
+    dir.join(format!("day{day}"))
+}
+
+/// Loads every fixture of a day from `dir`, sorted by name. A day without a directory simply has
+/// none.
+pub fn load(dir: &Path, day: u8) -> Result<Vec<Fixture>> {
+    let day_dir = day_dir(dir, day);
+    let entries = match fs::read_dir(&day_dir) {
+        Ok(entries) => entries,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(err) => {
+            return Err(err).wrap_err_with(|| format!("could not read {}", day_dir.display()))
+        }
+    };
+
+    let mut fixtures = Vec::new();
+    for entry in entries {
+        let path = entry?.path();
+        if path.extension().is_none_or(|ext| ext != "txt") {
+            continue;
+        }
+        let Some(name) = path.file_stem().and_then(|stem| stem.to_str()) else {
+            continue;
+        };
+
+        let input = fs::read_to_string(&path)
+            .wrap_err_with(|| format!("could not read fixture {}", path.display()))?;
+        let expected_path = path.with_extension("toml");
+        let expected = fs::read_to_string(&expected_path)
+            .wrap_err_with(|| format!("could not read {}", expected_path.display()))?;
+        let expected = toml::from_str(&expected)
+            .wrap_err_with(|| format!("invalid expectations in {}", expected_path.display()))?;
+        fixtures.push(Fixture {
+            name: name.to_owned(),
+            input,
+            expected,
+        });
+    }
+    fixtures.sort_by(|a, b| a.name.cmp(&b.name));
+    Ok(fixtures)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn loads_fixtures_in_order() {
+        let dir = tempfile::tempdir().unwrap();
+        let day_dir = day_dir(dir.path(), 1);
+        fs::create_dir(&day_dir).unwrap();
+        fs::write(day_dir.join("b.txt"), "2\n").unwrap();
+        fs::write(day_dir.join("b.toml"), "parse_error = \"line 1\"").unwrap();
+        fs::write(day_dir.join("a.txt"), "1\n").unwrap();
+        fs::write(day_dir.join("a.toml"), "part1 = \"1\"").unwrap();
+
+        let fixtures = load(dir.path(), 1).unwrap();
+        assert_eq!(
+            fixtures,
+            vec![
+                Fixture {
+                    name: "a".to_owned(),
+                    input: "1\n".to_owned(),
+                    expected: Expected {
+                        part1: Some("1".to_owned()),
+                        ..Expected::default()
+                    },
+                },
+                Fixture {
+                    name: "b".to_owned(),
+                    input: "2\n".to_owned(),
+                    expected: Expected {
+                        parse_error: Some("line 1".to_owned()),
+                        ..Expected::default()
+                    },
+                },
+            ]
+        );
+        assert_eq!(load(dir.path(), 2).unwrap(), vec![]);
+    }
+
+    #[test]
+    fn requires_expectations() {
+        let dir = tempfile::tempdir().unwrap();
+        let day_dir = day_dir(dir.path(), 1);
+        fs::create_dir(&day_dir).unwrap();
+        fs::write(day_dir.join("a.txt"), "1\n").unwrap();
+        assert!(load(dir.path(), 1).is_err());
+
+        fs::write(day_dir.join("a.toml"), "part3 = \"1\"").unwrap();
+        assert!(load(dir.path(), 1).is_err());
+    }
+}
@@ -0,0 +1,21 @@
+use super::Rng;
+use rand::Rng as _;
+
+/// About as many elves as a real input has.
+pub const DEFAULT_SIZE: usize = 250;
+
+/// Generates `size` elves, each carrying a handful of snacks, and at least the three that part 2
+/// adds up.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let size = size.max(3);
+    let mut input = String::new();
+    for elf in 0..size {
+        if elf > 0 {
+            input.push('\n');
+        }
+        for _ in 0..rng.gen_range(1..=15) {
+            input += &format!("{}\n", rng.gen_range(1000..=60_000));
+        }
+    }
+    input
+}
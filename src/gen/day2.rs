@@ -0,0 +1,17 @@
+use super::Rng;
+use rand::Rng as _;
+
+/// About as many rounds as a real input has.
+pub const DEFAULT_SIZE: usize = 2500;
+
+/// Generates `size` rounds of rock paper scissors.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut input = String::with_capacity(size * 4);
+    for _ in 0..size {
+        input.push((b'A' + rng.gen_range(0..3)) as char);
+        input.push(' ');
+        input.push((b'X' + rng.gen_range(0..3)) as char);
+        input.push('\n');
+    }
+    input
+}
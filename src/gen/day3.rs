@@ -0,0 +1,36 @@
+use super::Rng;
+use rand::{seq::SliceRandom as _, Rng as _};
+
+/// About as many rucksacks as a real input has.
+pub const DEFAULT_SIZE: usize = 300;
+
+const ITEMS: &[u8] = b"abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+/// Generates `size` rucksacks, rounded up to a whole number of groups of three.
+///
+/// Each rucksack has exactly one item in both compartments, and each group exactly one item (the
+/// badge) in all three rucksacks.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut input = String::new();
+    for _ in 0..size.div_ceil(3) {
+        let mut items = ITEMS.to_vec();
+        items.shuffle(rng);
+        let (badge, shared, rest) = (items[0], &items[1..4], &items[4..]);
+        for i in 0..3 {
+            // Every rucksack takes its own items from its own part of the rest, so nothing else
+            // can be in common.
+            let own = &rest[16 * i..16 * (i + 1)];
+            let extra = rng.gen_range(0..8);
+            let mut left = vec![badge, shared[i]];
+            left.extend(&own[..extra]);
+            let mut right = vec![shared[i]];
+            right.extend(&own[8..9 + extra]);
+            left.shuffle(rng);
+            right.shuffle(rng);
+
+            input.extend(left.into_iter().chain(right).map(char::from));
+            input.push('\n');
+        }
+    }
+    input
+}
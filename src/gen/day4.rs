@@ -0,0 +1,19 @@
+use super::Rng;
+use rand::Rng as _;
+
+/// About as many pairs as a real input has.
+pub const DEFAULT_SIZE: usize = 1000;
+
+/// Generates `size` pairs of elves, each assigned sections between 1 and 99.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut range = || {
+        let start = rng.gen_range(1..=99);
+        (start, rng.gen_range(start..=99))
+    };
+    let mut input = String::new();
+    for _ in 0..size {
+        let (a, b) = (range(), range());
+        input += &format!("{}-{},{}-{}\n", a.0, a.1, b.0, b.1);
+    }
+    input
+}
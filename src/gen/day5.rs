@@ -0,0 +1,63 @@
+use super::Rng;
+use rand::Rng as _;
+
+/// As many stacks as a real input has.
+pub const DEFAULT_SIZE: usize = 9;
+
+/// Generates `size` stacks of crates, and about as many moves per stack as a real input has.
+/// Every move only takes crates that are there.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let size = size.max(1);
+    let mut stacks: Vec<Vec<char>> = (0..size)
+        .map(|_| {
+            (0..rng.gen_range(0..=8))
+                .map(|_| rng.gen_range(b'A'..=b'Z') as char)
+                .collect()
+        })
+        .collect();
+    // Make sure there's something to move.
+    if stacks.iter().all(Vec::is_empty) {
+        stacks[0].push('A');
+    }
+
+    let mut heights: Vec<usize> = stacks.iter().map(Vec::len).collect();
+    let mut moves = Vec::new();
+    for _ in 0..size * 55 {
+        let from = loop {
+            let from = rng.gen_range(0..size);
+            if heights[from] > 0 {
+                break from;
+            }
+        };
+        let to = rng.gen_range(0..size);
+        let count = rng.gen_range(1..=heights[from].min(30));
+        heights[from] -= count;
+        heights[to] += count;
+        moves.push((count, from + 1, to + 1));
+    }
+    print(&stacks, &moves)
+}
+
+/// Draws the stacks, each listed from the bottom, and lists the moves, each as the number of
+/// crates and the stacks they go from and to, counting from 1.
+pub fn print(stacks: &[Vec<char>], moves: &[(usize, usize, usize)]) -> String {
+    let height = stacks.iter().map(Vec::len).max().unwrap_or(0);
+    let mut lines = Vec::new();
+    for level in (0..height).rev() {
+        let row: Vec<String> = stacks
+            .iter()
+            .map(|stack| match stack.get(level) {
+                Some(c) => format!("[{c}]"),
+                None => "   ".to_owned(),
+            })
+            .collect();
+        lines.push(row.join(" "));
+    }
+    let numbers: Vec<String> = (1..=stacks.len()).map(|n| format!("{n:^3}")).collect();
+    lines.push(numbers.join(" "));
+    lines.push(String::new());
+    for (count, from, to) in moves {
+        lines.push(format!("move {count} from {from} to {to}"));
+    }
+    lines.join("\n") + "\n"
+}
@@ -0,0 +1,22 @@
+use super::Rng;
+use rand::{seq::SliceRandom as _, Rng as _};
+
+/// As long as a real signal.
+pub const DEFAULT_SIZE: usize = 4096;
+
+/// Generates a signal of `size` letters, at least 14.
+///
+/// Most of it only uses three letters, so neither marker can be found until fourteen different
+/// ones turn up somewhere in it.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let size = size.max(14);
+    let mut signal: Vec<u8> = (0..size).map(|_| rng.gen_range(b'a'..=b'c')).collect();
+    let mut letters: Vec<u8> = (b'a'..=b'z').collect();
+    letters.shuffle(rng);
+    let at = rng.gen_range(0..=size - 14);
+    signal[at..at + 14].copy_from_slice(&letters[..14]);
+
+    let mut input: String = signal.into_iter().map(char::from).collect();
+    input.push('\n');
+    input
+}
@@ -0,0 +1,111 @@
+use super::Rng;
+use rand::Rng as _;
+use std::collections::BTreeMap;
+
+/// About as many lines as a real terminal session has.
+pub const DEFAULT_SIZE: usize = 1000;
+
+/// A file, with its size, or a directory, with its entries by name.
+#[derive(Debug, Clone)]
+pub enum Node {
+    File(u64),
+    Dir(BTreeMap<String, Node>),
+}
+
+#[derive(Default)]
+struct Dir {
+    /// The name and index of every directory in this one.
+    dirs: Vec<(String, usize)>,
+    files: Vec<(String, u64)>,
+}
+
+/// Generates a terminal session of about `size` lines, exploring every directory once.
+///
+/// The files add up to between 40M and 70M, so the disk isn't overfull, but there's still
+/// something to delete for part 2.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut dirs = vec![Dir::default()];
+    let mut file_count = 0;
+    // Going to `/` and listing it.
+    let mut lines = 2;
+    while lines < size {
+        let parent = rng.gen_range(0..dirs.len());
+        if dirs[parent].dirs.is_empty() && dirs[parent].files.is_empty() && parent != 0 {
+            // Going in, listing it, and coming back out.
+            lines += 3;
+        }
+        let name = format!("{}{}", name(rng), dirs.len() + file_count);
+        if rng.gen_bool(0.25) {
+            let child = dirs.len();
+            dirs[parent].dirs.push((name, child));
+            dirs.push(Dir::default());
+        } else {
+            dirs[parent].files.push((name, 0));
+            file_count += 1;
+        }
+        lines += 1;
+    }
+
+    let max_file_size = (20_000_000 / file_count.max(1) as u64).clamp(1, 300_000);
+    let mut used = 0;
+    for dir in &mut dirs {
+        for (_, size) in &mut dir.files {
+            *size = rng.gen_range(1..=max_file_size);
+            used += *size;
+        }
+    }
+    let target = rng.gen_range(40_000_001..=70_000_000);
+    if used < target {
+        // The other names all end in a number, so this can't be one of them.
+        dirs[0].files.push(("filler.dat".to_owned(), target - used));
+    }
+
+    print(&tree(&dirs, 0))
+}
+
+fn name(rng: &mut Rng) -> String {
+    (0..rng.gen_range(1..=6))
+        .map(|_| rng.gen_range(b'a'..=b'z') as char)
+        .collect()
+}
+
+fn tree(dirs: &[Dir], idx: usize) -> BTreeMap<String, Node> {
+    let dir = &dirs[idx];
+    let files = dir
+        .files
+        .iter()
+        .map(|(name, size)| (name.clone(), Node::File(*size)));
+    let subdirs = dir
+        .dirs
+        .iter()
+        .map(|(name, child)| (name.clone(), Node::Dir(tree(dirs, *child))));
+    files.chain(subdirs).collect()
+}
+
+/// Prints the commands that explore the whole file system from the entries in `/`, depth first.
+pub fn print(root: &BTreeMap<String, Node>) -> String {
+    fn visit(entries: &BTreeMap<String, Node>, lines: &mut Vec<String>) {
+        lines.push("$ ls".to_owned());
+        for (name, node) in entries {
+            match node {
+                Node::File(size) => lines.push(format!("{size} {name}")),
+                Node::Dir(_) => lines.push(format!("dir {name}")),
+            }
+        }
+        for (name, node) in entries {
+            // Empty directories have nothing to explore.
+            match node {
+                Node::Dir(entries) if !entries.is_empty() => {
+                    lines.push(format!("$ cd {name}"));
+                    visit(entries, lines);
+                    lines.push("$ cd ..".to_owned());
+                }
+                _ => {}
+            }
+        }
+    }
+
+    let mut lines = vec!["$ cd /".to_owned()];
+    visit(root, &mut lines);
+    lines.join("\n") + "\n"
+}
@@ -0,0 +1,18 @@
+use super::Rng;
+use rand::Rng as _;
+
+/// As wide as a real forest.
+pub const DEFAULT_SIZE: usize = 99;
+
+/// Generates a square forest `size` trees wide, at least one.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let size = size.max(1);
+    let mut input = String::with_capacity(size * (size + 1));
+    for _ in 0..size {
+        for _ in 0..size {
+            input.push((b'0' + rng.gen_range(0..10)) as char);
+        }
+        input.push('\n');
+    }
+    input
+}
@@ -0,0 +1,94 @@
+use eyre::{eyre, Result};
+use rand::SeedableRng as _;
+use rand_chacha::ChaCha8Rng;
+
+pub mod day1;
+pub mod day2;
+pub mod day3;
+pub mod day4;
+pub mod day5;
+pub mod day6;
+pub mod day7;
+pub mod day8;
+
+/// The random number generator every generator draws from. It's the same on every platform and
+/// version, so a seed always gives the same input.
+pub type Rng = ChaCha8Rng;
+
+/// Every day with a generator, in order.
+pub const GENERATORS: &[Generator] = &[
+    Generator::new(1, day1::DEFAULT_SIZE, day1::generate),
+    Generator::new(2, day2::DEFAULT_SIZE, day2::generate),
+    Generator::new(3, day3::DEFAULT_SIZE, day3::generate),
+    Generator::new(4, day4::DEFAULT_SIZE, day4::generate),
+    Generator::new(5, day5::DEFAULT_SIZE, day5::generate),
+    Generator::new(6, day6::DEFAULT_SIZE, day6::generate),
+    Generator::new(7, day7::DEFAULT_SIZE, day7::generate),
+    Generator::new(8, day8::DEFAULT_SIZE, day8::generate),
+];
+
+pub fn get(day: u8) -> Result<&'static Generator> {
+    GENERATORS
+        .iter()
+        .find(|generator| generator.day == day)
+        .ok_or_else(|| eyre!("day {day} has no generator"))
+}
+
+/// Makes up puzzle inputs for a day, which its `parse` always accepts and both parts can solve.
+///
+/// What the size counts differs between days, like elves for day 1 or the width of the forest for
+/// day 8, but the default is always about as big as a real input.
+pub struct Generator {
+    pub day: u8,
+    pub default_size: usize,
+    generate: fn(&mut Rng, usize) -> String,
+}
+
+impl Generator {
+    const fn new(day: u8, default_size: usize, generate: fn(&mut Rng, usize) -> String) -> Self {
+        Generator {
+            day,
+            default_size,
+            generate,
+        }
+    }
+
+    pub fn generate(&self, seed: u64, size: usize) -> String {
+        (self.generate)(&mut Rng::seed_from_u64(seed), size)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::days::{self, Part};
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn generates_solvable_inputs() {
+        for generator in GENERATORS {
+            let day = days::get(generator.day).unwrap();
+            // Including sizes too small for a real input.
+            let sizes = (0..5).map(|seed| (seed, generator.default_size));
+            for (seed, size) in sizes.chain([(0, 0), (0, 1), (0, 2)]) {
+                let input = generator.generate(seed, size);
+                for part in Part::BOTH {
+                    if let Err(err) = day.run(&input, part) {
+                        panic!(
+                            "day {}, seed {seed}, size {size}, part {part}: {err:?}",
+                            day.number
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn is_deterministic() {
+        for generator in GENERATORS {
+            assert_eq!(generator.generate(7, 20), generator.generate(7, 20));
+            assert_ne!(generator.generate(7, 20), generator.generate(8, 20));
+        }
+    }
+}
@@ -0,0 +1,219 @@
+use eyre::{eyre, Result, WrapErr as _};
+use std::env;
+
+/// The Advent of Code website.
+pub const BASE_URL: &str = "https://adventofcode.com";
+
+/// The environment variable holding the `session` cookie of a logged in browser.
+pub const SESSION_VAR: &str = "AOC_SESSION";
+
+/// Advent of Code asks automated tools to say who they are.
+const USER_AGENT: &str = "github.com/Proximyst/aoc-2022";
+
+/// A way to talk to the Advent of Code website, so tests can point it at a local server instead.
+pub trait Http {
+    /// Fetches a path, like `/2022/day/1/input`, and returns the body.
+    fn get(&self, path: &str) -> Result<String>;
+
+    /// Posts a form to a path, like `/2022/day/1/answer`, and returns the body.
+    fn post_form(&self, path: &str, form: &[(&str, &str)]) -> Result<String>;
+}
+
+/// The session in `AOC_SESSION`, if it is set.
+pub fn session_from_env() -> Option<String> {
+    env::var(SESSION_VAR)
+        .ok()
+        .map(|session| session.trim().to_owned())
+        .filter(|session| !session.is_empty())
+}
+
+/// Talks to the website over HTTP(S), logged in with a session cookie.
+pub struct Client {
+    base_url: String,
+    session: String,
+    agent: ureq::Agent,
+}
+
+impl Client {
+    pub fn new(session: impl Into<String>) -> Self {
+        Client::with_base_url(BASE_URL, session)
+    }
+
+    /// Creates a client for a website other than the real one, like a stub server in tests.
+    pub fn with_base_url(base_url: impl Into<String>, session: impl Into<String>) -> Self {
+        Client {
+            base_url: base_url.into(),
+            session: session.into(),
+            agent: ureq::AgentBuilder::new().user_agent(USER_AGENT).build(),
+        }
+    }
+
+    fn read(&self, url: &str, response: Result<ureq::Response, ureq::Error>) -> Result<String> {
+        match response {
+            Ok(response) => response
+                .into_string()
+                .wrap_err_with(|| format!("could not read response from {url}")),
+            Err(ureq::Error::Status(code, response)) => {
+                let body = response.into_string().unwrap_or_default();
+                Err(eyre!("{url} responded with {code}: {}", body.trim()))
+            }
+            Err(err) => Err(err).wrap_err_with(|| format!("could not connect to {url}")),
+        }
+    }
+}
+
+impl Http for Client {
+    fn get(&self, path: &str) -> Result<String> {
+        let url = format!("{}{path}", self.base_url);
+        let response = self
+            .agent
+            .get(&url)
+            .set("Cookie", &format!("session={}", self.session))
+            .call();
+        self.read(&url, response)
+    }
+
+    fn post_form(&self, path: &str, form: &[(&str, &str)]) -> Result<String> {
+        let url = format!("{}{path}", self.base_url);
+        let response = self
+            .agent
+            .post(&url)
+            .set("Cookie", &format!("session={}", self.session))
+            .send_form(form);
+        self.read(&url, response)
+    }
+}
+
+/// A tiny HTTP server for tests, which answers each connection with the next canned response.
+#[cfg(test)]
+pub(crate) mod stub {
+    use std::{
+        io::{BufRead as _, BufReader, Read as _, Write as _},
+        net::TcpListener,
+        sync::mpsc,
+        thread,
+    };
+
+    /// A request the stub server received.
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub struct Request {
+        pub method: String,
+        pub path: String,
+        pub headers: Vec<(String, String)>,
+        pub body: String,
+    }
+
+    impl Request {
+        pub fn header(&self, name: &str) -> Option<&str> {
+            self.headers
+                .iter()
+                .find(|(n, _)| n.eq_ignore_ascii_case(name))
+                .map(|(_, v)| v.as_str())
+        }
+    }
+
+    pub struct Server {
+        pub base_url: String,
+        requests: mpsc::Receiver<Request>,
+    }
+
+    impl Server {
+        /// Every request received so far.
+        pub fn requests(&self) -> Vec<Request> {
+            self.requests.try_iter().collect()
+        }
+    }
+
+    /// Starts a server that answers with `responses`, a status and body each, in order.
+    pub fn serve(responses: Vec<(u16, &'static str)>) -> Server {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        let (tx, requests) = mpsc::channel();
+        thread::spawn(move || {
+            for (status, body) in responses {
+                let (stream, _) = listener.accept().unwrap();
+                let mut reader = BufReader::new(stream);
+
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                let mut parts = line.split_whitespace();
+                let method = parts.next().unwrap_or_default().to_owned();
+                let path = parts.next().unwrap_or_default().to_owned();
+
+                let mut headers = Vec::new();
+                loop {
+                    let mut line = String::new();
+                    reader.read_line(&mut line).unwrap();
+                    let Some((name, value)) = line.trim_end().split_once(": ") else {
+                        break;
+                    };
+                    headers.push((name.to_owned(), value.to_owned()));
+                }
+                let length = headers
+                    .iter()
+                    .find(|(n, _)| n.eq_ignore_ascii_case("content-length"))
+                    .map_or(0, |(_, v)| v.parse().unwrap());
+                let mut request_body = vec![0; length];
+                reader.read_exact(&mut request_body).unwrap();
+
+                let _ = tx.send(Request {
+                    method,
+                    path,
+                    headers,
+                    body: String::from_utf8(request_body).unwrap(),
+                });
+                let _ = write!(
+                    reader.get_mut(),
+                    "HTTP/1.1 {status} Stub\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                    body.len(),
+                );
+            }
+        });
+        Server { base_url, requests }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn gets_with_session() {
+        let server = stub::serve(vec![(200, "1\n2\n")]);
+        let client = Client::with_base_url(&server.base_url, "abc");
+        assert_eq!(client.get("/2022/day/1/input").unwrap(), "1\n2\n");
+
+        let requests = server.requests();
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0].method, "GET");
+        assert_eq!(requests[0].path, "/2022/day/1/input");
+        assert_eq!(requests[0].header("cookie"), Some("session=abc"));
+        assert_eq!(requests[0].header("user-agent"), Some(USER_AGENT));
+    }
+
+    #[test]
+    fn posts_form() {
+        let server = stub::serve(vec![(200, "ok")]);
+        let client = Client::with_base_url(&server.base_url, "abc");
+        let body = client
+            .post_form("/2022/day/1/answer", &[("level", "1"), ("answer", "a b")])
+            .unwrap();
+        assert_eq!(body, "ok");
+
+        let requests = server.requests();
+        assert_eq!(requests[0].method, "POST");
+        assert_eq!(requests[0].body, "level=1&answer=a+b");
+        assert_eq!(requests[0].header("cookie"), Some("session=abc"));
+    }
+
+    #[test]
+    fn errors_on_bad_status() {
+        let server = stub::serve(vec![(400, "Please log in.\n")]);
+        let client = Client::with_base_url(&server.base_url, "abc");
+        let err = client.get("/2022/day/1/input").unwrap_err();
+        assert!(err
+            .to_string()
+            .ends_with("responded with 400: Please log in."));
+    }
+}
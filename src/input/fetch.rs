@@ -0,0 +1,218 @@
+use crate::http::{self, Client, Http};
+use eyre::{Result, WrapErr as _};
+use sha2::{Digest as _, Sha256};
+use std::{
+    env, fs, io,
+    path::{Path, PathBuf},
+    thread,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+/// The event every day is fetched from.
+pub const YEAR: u16 = 2022;
+
+/// The environment variable overriding where fetched inputs are cached.
+pub const CACHE_DIR_VAR: &str = "AOC_CACHE_DIR";
+
+/// How long to wait between requests by default, so we don't hammer the website.
+pub const DEFAULT_MIN_INTERVAL: Duration = Duration::from_secs(5);
+
+/// The file in the cache directory recording when the last request was made, so the rate limit
+/// holds across runs.
+const LAST_REQUEST_FILE: &str = ".last-request";
+
+/// Downloads puzzle inputs, caching them on disk so each is only ever fetched once.
+///
+/// Cached inputs are stored as `dayN-<hash>.txt`, where the hash is of the contents; a file whose
+/// contents don't match its name is considered corrupt, and fetched again.
+pub struct Fetcher<H> {
+    http: H,
+    cache_dir: PathBuf,
+    min_interval: Duration,
+}
+
+impl Fetcher<Client> {
+    /// Creates a fetcher logged in with the session in `AOC_SESSION`, if it is set.
+    ///
+    /// Each session gets its own cache directory, as every account has different inputs.
+    pub fn from_env() -> Option<Self> {
+        let session = http::session_from_env()?;
+        let cache_dir = default_cache_dir().join(&hash(&session)[..16]);
+        Some(Fetcher::new(Client::new(session), cache_dir))
+    }
+}
+
+impl<H: Http> Fetcher<H> {
+    pub fn new(http: H, cache_dir: PathBuf) -> Self {
+        Fetcher {
+            http,
+            cache_dir,
+            min_interval: DEFAULT_MIN_INTERVAL,
+        }
+    }
+
+    /// Sets the least amount of time to leave between two requests.
+    pub fn with_min_interval(mut self, min_interval: Duration) -> Self {
+        self.min_interval = min_interval;
+        self
+    }
+
+    /// Returns the input for a day, from the cache if possible.
+    pub fn fetch(&self, day: u8) -> Result<String> {
+        if let Some(input) = self.cached(day)? {
+            return Ok(input);
+        }
+
+        fs::create_dir_all(&self.cache_dir)
+            .wrap_err_with(|| format!("could not create cache dir {}", self.cache_dir.display()))?;
+        self.wait_for_rate_limit()?;
+        let input = self.http.get(&format!("/{YEAR}/day/{day}/input"))?;
+
+        let path = self
+            .cache_dir
+            .join(format!("day{day}-{}.txt", &hash(&input)[..16]));
+        fs::write(&path, &input)
+            .wrap_err_with(|| format!("could not cache input in {}", path.display()))?;
+        Ok(input)
+    }
+
+    fn cached(&self, day: u8) -> Result<Option<String>> {
+        let entries = match fs::read_dir(&self.cache_dir) {
+            Ok(entries) => entries,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(None),
+            Err(err) => return Err(err).wrap_err("could not read cache dir"),
+        };
+
+        let prefix = format!("day{day}-");
+        for entry in entries {
+            let path = entry?.path();
+            let Some(hash_of_name) = path
+                .file_name()
+                .and_then(|name| name.to_str())
+                .and_then(|name| name.strip_prefix(&prefix))
+                .and_then(|name| name.strip_suffix(".txt"))
+            else {
+                continue;
+            };
+
+            let input = fs::read_to_string(&path)
+                .wrap_err_with(|| format!("could not read cached input {}", path.display()))?;
+            if hash(&input).starts_with(hash_of_name) {
+                return Ok(Some(input));
+            }
+            // It was corrupted somehow, so pretend we never had it.
+            fs::remove_file(&path)?;
+        }
+        Ok(None)
+    }
+
+    fn wait_for_rate_limit(&self) -> Result<()> {
+        let path = self.cache_dir.join(LAST_REQUEST_FILE);
+        let last_request = fs::read_to_string(&path)
+            .ok()
+            .and_then(|nanos| nanos.trim().parse().ok())
+            .map(|nanos| UNIX_EPOCH + Duration::from_nanos(nanos));
+        if let Some(last_request) = last_request {
+            // If the clock went backwards, the elapsed time is unknown, so wait the whole interval.
+            let elapsed = SystemTime::now()
+                .duration_since(last_request)
+                .unwrap_or_default();
+            thread::sleep(self.min_interval.saturating_sub(elapsed));
+        }
+
+        // In full nanoseconds, as rounding it down would make the next wait too short.
+        let now = SystemTime::now().duration_since(UNIX_EPOCH)?.as_nanos();
+        fs::write(&path, now.to_string())
+            .wrap_err_with(|| format!("could not write {}", path.display()))
+    }
+}
+
+/// The directory fetched inputs are cached in: `AOC_CACHE_DIR` if set, or the user's cache dir.
+pub fn default_cache_dir() -> PathBuf {
+    if let Some(dir) = env::var_os(CACHE_DIR_VAR) {
+        return PathBuf::from(dir);
+    }
+    let base = env::var_os("XDG_CACHE_HOME")
+        .map(PathBuf::from)
+        .or_else(|| env::var_os("HOME").map(|home| Path::new(&home).join(".cache")))
+        .unwrap_or_else(env::temp_dir);
+    base.join("aoc2022")
+}
+
+fn hash(s: &str) -> String {
+    Sha256::digest(s.as_bytes())
+        .iter()
+        .map(|b| format!("{b:02x}"))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::http::stub;
+    use pretty_assertions::assert_eq;
+    use std::time::Instant;
+
+    fn fetcher(server: &stub::Server, cache_dir: &Path) -> Fetcher<Client> {
+        Fetcher::new(
+            Client::with_base_url(&server.base_url, "abc"),
+            cache_dir.to_owned(),
+        )
+        .with_min_interval(Duration::ZERO)
+    }
+
+    #[test]
+    fn fetches_once_then_caches() {
+        let cache = tempfile::tempdir().unwrap();
+        let server = stub::serve(vec![(200, "1\n2\n")]);
+        let fetcher = fetcher(&server, cache.path());
+
+        assert_eq!(fetcher.fetch(1).unwrap(), "1\n2\n");
+        assert_eq!(fetcher.fetch(1).unwrap(), "1\n2\n");
+
+        let requests = server.requests();
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0].path, "/2022/day/1/input");
+        let cached = cache
+            .path()
+            .join(format!("day1-{}.txt", &hash("1\n2\n")[..16]));
+        assert_eq!(fs::read_to_string(cached).unwrap(), "1\n2\n");
+    }
+
+    #[test]
+    fn refetches_corrupt_cache() {
+        let cache = tempfile::tempdir().unwrap();
+        let server = stub::serve(vec![(200, "1\n2\n"), (200, "1\n2\n")]);
+        let fetcher = fetcher(&server, cache.path());
+
+        fetcher.fetch(1).unwrap();
+        let cached = cache
+            .path()
+            .join(format!("day1-{}.txt", &hash("1\n2\n")[..16]));
+        fs::write(&cached, "1\n").unwrap();
+        assert_eq!(fetcher.fetch(1).unwrap(), "1\n2\n");
+        assert_eq!(server.requests().len(), 2);
+    }
+
+    #[test]
+    fn waits_between_requests() {
+        let cache = tempfile::tempdir().unwrap();
+        let server = stub::serve(vec![(200, "1\n"), (200, "2\n")]);
+        let fetcher = fetcher(&server, cache.path()).with_min_interval(Duration::from_millis(300));
+
+        let start = Instant::now();
+        fetcher.fetch(1).unwrap();
+        fetcher.fetch(2).unwrap();
+        assert!(start.elapsed() >= Duration::from_millis(300));
+    }
+
+    #[test]
+    fn does_not_cache_errors() {
+        let cache = tempfile::tempdir().unwrap();
+        let server = stub::serve(vec![(404, "Not Found")]);
+        let fetcher = fetcher(&server, cache.path());
+
+        assert!(fetcher.fetch(26).is_err());
+        assert!(fetcher.cached(26).unwrap().is_none());
+    }
+}
@@ -0,0 +1,91 @@
+use self::fetch::Fetcher;
+use crate::days::Day;
+use color_eyre::Section as _;
+use eyre::{eyre, Result, WrapErr as _};
+use std::{
+    fs,
+    io::{self, Read as _},
+    path::{Path, PathBuf},
+};
+
+pub mod fetch;
+mod text;
+
+pub use text::Input;
+
+/// The directory puzzle inputs are looked up in when none is given explicitly.
+pub const INPUTS_DIR: &str = "inputs";
+
+/// The conventional location of a day's input: `inputs/dayN.txt`.
+pub fn default_path(day: &Day) -> PathBuf {
+    Path::new(INPUTS_DIR).join(format!("day{}.txt", day.number))
+}
+
+/// Loads the puzzle input for a day.
+///
+/// An explicit path always wins, with `-` meaning stdin. Otherwise, the input is read from
+/// [`default_path`], falling back on the embedded input if the `embed-inputs` feature is enabled,
+/// and then on fetching it from the website if `AOC_SESSION` is set.
+pub fn load(day: &Day, explicit: Option<&Path>) -> Result<String> {
+    match explicit {
+        Some(path) if path == Path::new("-") => {
+            let mut input = String::new();
+            io::stdin()
+                .read_to_string(&mut input)
+                .wrap_err("could not read input from stdin")?;
+            Ok(input)
+        }
+        Some(path) => read(path),
+        None => {
+            let path = default_path(day);
+            if path.exists() {
+                return read(&path);
+            }
+            if let Some(input) = day.embedded_input {
+                return Ok(input.to_owned());
+            }
+            match Fetcher::from_env() {
+                Some(fetcher) => fetcher
+                    .fetch(day.number)
+                    .wrap_err_with(|| format!("could not fetch input for day {}", day.number)),
+                None => Err(
+                    eyre!("no input found for day {}", day.number).suggestion(format!(
+                        "pass --input <path> (or `-` for stdin), save it as {}, or set {} to \
+                         fetch it",
+                        path.display(),
+                        crate::http::SESSION_VAR,
+                    )),
+                ),
+            }
+        }
+    }
+}
+
+fn read(path: &Path) -> Result<String> {
+    fs::read_to_string(path)
+        .wrap_err_with(|| format!("could not read input from {}", path.display()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::days;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn explicit_path_is_read() {
+        let file = tempfile::NamedTempFile::new().unwrap();
+        fs::write(file.path(), "1\n2\n").unwrap();
+        assert_eq!(
+            load(days::get(1).unwrap(), Some(file.path())).unwrap(),
+            "1\n2\n"
+        );
+    }
+
+    #[test]
+    fn missing_explicit_path_errors() {
+        let path = Path::new("this/does/not/exist.txt");
+        let err = load(days::get(1).unwrap(), Some(path)).unwrap_err();
+        assert!(err.to_string().contains("this/does/not/exist.txt"));
+    }
+}
@@ -0,0 +1,123 @@
+use crate::ParseError;
+use std::{borrow::Cow, iter, str::Lines};
+
+/// A puzzle input as the days see it, with the differences in how it might have been saved
+/// evened out: `\r\n` line endings become `\n`, and a single trailing newline is dropped.
+///
+/// Line numbers are kept as they were, so errors still point at the right place.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Input<'a> {
+    text: Cow<'a, str>,
+}
+
+impl<'a> Input<'a> {
+    pub fn new(raw: &'a str) -> Self {
+        let text = if raw.contains("\r\n") {
+            let mut text = raw.replace("\r\n", "\n");
+            if text.ends_with('\n') {
+                text.pop();
+            }
+            Cow::Owned(text)
+        } else {
+            Cow::Borrowed(raw.strip_suffix('\n').unwrap_or(raw))
+        };
+        Input { text }
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.text
+    }
+
+    pub fn lines(&self) -> Lines<'_> {
+        self.text.lines()
+    }
+
+    /// The groups of lines separated by blank ones, like the elves of day 1, with the 0-based
+    /// index of each line. Any number of blank or whitespace-only lines separate two groups.
+    pub fn paragraphs(&self) -> impl Iterator<Item = Vec<(usize, &str)>> {
+        let mut lines = self.lines().enumerate().peekable();
+        iter::from_fn(move || {
+            while lines.next_if(|(_, line)| line.trim().is_empty()).is_some() {}
+            let paragraph: Vec<_> =
+                iter::from_fn(|| lines.next_if(|(_, line)| !line.trim().is_empty())).collect();
+            (!paragraph.is_empty()).then_some(paragraph)
+        })
+    }
+
+    /// Parses a rectangular grid of cells, one per character, like the forest of day 8.
+    ///
+    /// Whitespace at the end of a line, or at the end of the input, is not part of the grid. Any
+    /// character `cell` turns down is reported as an error expecting `expected`.
+    pub fn grid<T>(
+        &self,
+        day: u8,
+        expected: &str,
+        mut cell: impl FnMut(char) -> Option<T>,
+    ) -> Result<Vec<Vec<T>>, ParseError> {
+        let mut rows: Vec<Vec<T>> = Vec::new();
+        for (idx, line) in self.text.trim_end().lines().enumerate() {
+            let trimmed = line.trim_end();
+            let row = trimmed
+                .char_indices()
+                .map(|(i, c)| {
+                    cell(c).ok_or_else(|| {
+                        let found = &trimmed[i..i + c.len_utf8()];
+                        ParseError::new(day, idx, line, found, expected)
+                    })
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+            if let Some(first) = rows.first() {
+                if row.len() != first.len() {
+                    let expected = format!("a row of {} cells, like the first", first.len());
+                    return Err(ParseError::new(day, idx, line, trimmed, expected));
+                }
+            }
+            rows.push(row);
+        }
+        if rows.is_empty() {
+            return Err(ParseError::missing(day, 0, "", "a row of the grid"));
+        }
+        Ok(rows)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn normalises_line_endings() {
+        assert_eq!(Input::new("a\r\nb\r\n").as_str(), "a\nb");
+        assert_eq!(Input::new("a\nb\n\n").as_str(), "a\nb\n");
+        assert_eq!(Input::new("a\rb").as_str(), "a\rb");
+        assert!(matches!(Input::new("a\nb\n").text, Cow::Borrowed("a\nb")));
+    }
+
+    #[test]
+    fn splits_paragraphs() {
+        let input = Input::new("1\n2\n\n3\r\n \r\n\r\n4\r\n");
+        assert_eq!(
+            input.paragraphs().collect::<Vec<_>>(),
+            [vec![(0, "1"), (1, "2")], vec![(3, "3")], vec![(6, "4")]],
+        );
+        assert_eq!(Input::new("\n\n").paragraphs().count(), 0);
+    }
+
+    #[test]
+    fn parses_grids() {
+        let digit = |c: char| c.to_digit(10);
+        assert_eq!(
+            Input::new("12 \r\n34\r\n\r\n")
+                .grid(8, "a digit", digit)
+                .unwrap(),
+            [[1, 2], [3, 4]],
+        );
+
+        let err = Input::new("12\n3x").grid(8, "a digit", digit).unwrap_err();
+        assert_eq!((err.line, err.column, err.found.as_str()), (2, 2, "x"));
+        let err = Input::new("12\n345").grid(8, "a digit", digit).unwrap_err();
+        assert_eq!((err.line, err.column, err.found.as_str()), (2, 1, "345"));
+        assert!(Input::new(" \n").grid(8, "a digit", digit).is_err());
+    }
+}
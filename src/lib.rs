@@ -0,0 +1,22 @@
+pub mod answers;
+pub mod bench;
+pub mod days;
+pub mod fixtures;
+pub mod gen;
+pub mod http;
+pub mod input;
+pub mod output;
+pub mod parallel;
+pub mod parsing;
+pub mod report;
+pub mod scaffold;
+pub mod submit;
+pub mod watch;
+
+mod answer;
+mod error;
+mod solution;
+
+pub use answer::Answer;
+pub use error::ParseError;
+pub use solution::Solution;
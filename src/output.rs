@@ -0,0 +1,126 @@
+use crate::days::Part;
+use eyre::{eyre, Result};
+use serde::Serialize;
+use std::{str::FromStr, time::Duration};
+
+/// How the runner prints its results.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Format {
+    /// `Day 1, part 2: 45000`, for humans.
+    #[default]
+    Plain,
+    /// One JSON object per line.
+    Json,
+    /// Tab-separated values, with a header line.
+    Tsv,
+}
+
+impl FromStr for Format {
+    type Err = eyre::Report;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "plain" => Ok(Format::Plain),
+            "json" => Ok(Format::Json),
+            "tsv" => Ok(Format::Tsv),
+            _ => Err(eyre!("format must be plain, json or tsv, not {s:?}")),
+        }
+    }
+}
+
+/// The result of solving a single part, with a stable schema for the machine-readable formats.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Record {
+    pub day: u8,
+    pub part: u8,
+    pub answer: String,
+    /// How long solving the part took, not counting parsing the input.
+    pub elapsed_ns: u64,
+}
+
+impl Record {
+    pub fn new(day: u8, part: Part, answer: impl ToString, elapsed: Duration) -> Self {
+        Record {
+            day,
+            part: part.number(),
+            answer: answer.to_string(),
+            elapsed_ns: u64::try_from(elapsed.as_nanos()).unwrap_or(u64::MAX),
+        }
+    }
+}
+
+impl Format {
+    /// The line to print before any records, if any.
+    pub fn header(self) -> Option<&'static str> {
+        match self {
+            Format::Tsv => Some("day\tpart\tanswer\telapsed_ns"),
+            Format::Plain | Format::Json => None,
+        }
+    }
+
+    pub fn format(self, record: &Record) -> String {
+        match self {
+            // Multi-line answers are easier to read when they aren't offset by the prefix.
+            Format::Plain if record.answer.contains('\n') => format!(
+                "Day {}, part {}:\n{}",
+                record.day, record.part, record.answer
+            ),
+            Format::Plain => format!(
+                "Day {}, part {}: {}",
+                record.day, record.part, record.answer
+            ),
+            Format::Json => serde_json::to_string(record).expect("records always serialise"),
+            Format::Tsv => format!(
+                "{}\t{}\t{}\t{}",
+                record.day,
+                record.part,
+                escape_tsv(&record.answer),
+                record.elapsed_ns
+            ),
+        }
+    }
+}
+
+/// Escapes the characters that would break a TSV row, the same way PostgreSQL's `COPY` does.
+fn escape_tsv(s: &str) -> String {
+    s.replace('\\', "\\\\")
+        .replace('\t', "\\t")
+        .replace('\n', "\\n")
+        .replace('\r', "\\r")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    fn record(answer: &str) -> Record {
+        Record::new(5, Part::One, answer, Duration::from_micros(12))
+    }
+
+    #[test]
+    fn formats_plain() {
+        assert_eq!(Format::Plain.format(&record("CMZ")), "Day 5, part 1: CMZ");
+        assert_eq!(
+            Format::Plain.format(&record("#.\n.#")),
+            "Day 5, part 1:\n#.\n.#"
+        );
+    }
+
+    #[test]
+    fn formats_json() {
+        assert_eq!(
+            Format::Json.format(&record("CMZ")),
+            r#"{"day":5,"part":1,"answer":"CMZ","elapsed_ns":12000}"#,
+        );
+    }
+
+    #[test]
+    fn formats_tsv() {
+        assert_eq!(Format::Tsv.format(&record("CMZ")), "5\t1\tCMZ\t12000");
+        assert_eq!(
+            Format::Tsv.format(&record("a\tb\nc\\")),
+            "5\t1\ta\\tb\\nc\\\\\t12000"
+        );
+    }
+}
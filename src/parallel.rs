@@ -0,0 +1,215 @@
+use crate::{
+    days::{Day, Part},
+    output::Record,
+};
+use eyre::{eyre, Result};
+use std::{
+    any::Any,
+    fmt::Write as _,
+    num::NonZeroUsize,
+    panic::{self, AssertUnwindSafe},
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Mutex,
+    },
+    thread,
+    time::{Duration, Instant},
+};
+
+/// What came of running a single day on its own thread.
+#[derive(Debug)]
+pub struct DayRun {
+    pub day: u8,
+    /// How long the whole day took, from loading the input to solving the last part.
+    pub elapsed: Duration,
+    /// The answer to each part, or why the day failed, including if it panicked.
+    pub outcome: Result<Vec<Record>>,
+}
+
+/// How many jobs to run at once when none are asked for: one per core.
+pub fn default_jobs() -> usize {
+    thread::available_parallelism().map_or(1, NonZeroUsize::get)
+}
+
+/// Runs the given parts of every day on a pool of `jobs` threads, and returns what came of each in
+/// the order of `days`.
+///
+/// Each day loads its own input with `load`, so slow reads or fetches overlap too. A day that
+/// errors or panics only fails itself: the others carry on regardless.
+pub fn run(
+    days: &[&Day],
+    parts: &[Part],
+    jobs: usize,
+    load: impl Fn(&Day) -> Result<String> + Sync,
+) -> Vec<DayRun> {
+    let next = AtomicUsize::new(0);
+    let runs = Mutex::new(Vec::with_capacity(days.len()));
+    thread::scope(|scope| {
+        for _ in 0..jobs.clamp(1, days.len().max(1)) {
+            scope.spawn(|| {
+                while let Some(&day) = days.get(next.fetch_add(1, Ordering::Relaxed)) {
+                    let start = Instant::now();
+                    let outcome = panic::catch_unwind(AssertUnwindSafe(|| {
+                        let parsed = day.parse(&load(day)?)?;
+                        parts
+                            .iter()
+                            .map(|&part| {
+                                let start = Instant::now();
+                                let answer = day.solve(&*parsed, part)?;
+                                Ok(Record::new(day.number, part, answer, start.elapsed()))
+                            })
+                            .collect()
+                    }))
+                    .unwrap_or_else(|payload| Err(eyre!("panicked: {}", panic_message(&*payload))));
+                    let run = DayRun {
+                        day: day.number,
+                        elapsed: start.elapsed(),
+                        outcome,
+                    };
+                    runs.lock().unwrap_or_else(|err| err.into_inner()).push(run);
+                }
+            });
+        }
+    });
+
+    let mut runs = runs.into_inner().unwrap_or_else(|err| err.into_inner());
+    runs.sort_by_key(|run| days.iter().position(|day| day.number == run.day));
+    runs
+}
+
+pub(crate) fn panic_message(payload: &(dyn Any + Send)) -> &str {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message
+    } else {
+        "with a non-string payload"
+    }
+}
+
+/// Lays out the runs as a table, with a column per part and a column for each day's status.
+/// Multi-line answers are squashed onto one line so they keep to their row.
+pub fn summary(runs: &[DayRun], parts: &[Part]) -> String {
+    let mut rows = vec![{
+        let mut header = vec!["day".to_owned()];
+        header.extend(parts.iter().map(|part| format!("part {part}")));
+        header.extend(["time".to_owned(), "status".to_owned()]);
+        header
+    }];
+    for run in runs {
+        let mut row = vec![run.day.to_string()];
+        match &run.outcome {
+            Ok(records) => {
+                row.extend(
+                    records
+                        .iter()
+                        .map(|r| r.answer.lines().collect::<Vec<_>>().join(" / ")),
+                );
+                row.extend([format!("{:.2?}", run.elapsed), "ok".to_owned()]);
+            }
+            Err(err) => {
+                row.extend(parts.iter().map(|_| "-".to_owned()));
+                row.extend([format!("{:.2?}", run.elapsed), format!("FAILED, {err}")]);
+            }
+        }
+        rows.push(row);
+    }
+
+    let columns = rows[0].len();
+    let widths: Vec<usize> = (0..columns)
+        .map(|col| {
+            rows.iter()
+                .map(|row| row[col].chars().count())
+                .max()
+                .unwrap_or(0)
+        })
+        .collect();
+    let mut table = String::new();
+    for row in &rows {
+        let mut line = String::new();
+        for (col, (cell, width)) in row.iter().zip(&widths).enumerate() {
+            let _ = match col {
+                0 => write!(line, "{cell:>width$}"),
+                _ if col == columns - 2 => write!(line, "  {cell:>width$}"),
+                _ if col == columns - 1 => write!(line, "  {cell}"),
+                _ => write!(line, "  {cell:<width$}"),
+            };
+        }
+        table.push_str(line.trim_end());
+        table.push('\n');
+    }
+    table
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::days;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn isolates_failing_days() {
+        let days: Vec<_> = [1, 2, 6].map(|n| days::get(n).unwrap()).to_vec();
+        let runs = run(&days, &Part::BOTH, 2, |day| match day.number {
+            1 => Ok("1000\n2000\n\n3000\n\n4000\n".to_owned()),
+            2 => panic!("no input for you"),
+            _ => Err(eyre!("no input found")),
+        });
+
+        assert_eq!(
+            runs.iter().map(|run| run.day).collect::<Vec<_>>(),
+            [1, 2, 6]
+        );
+        let answers: Vec<_> = runs[0]
+            .outcome
+            .as_ref()
+            .unwrap()
+            .iter()
+            .map(|r| &r.answer)
+            .collect();
+        assert_eq!(answers, ["4000", "10000"]);
+        let errors: Vec<_> = runs[1..]
+            .iter()
+            .map(|run| run.outcome.as_ref().unwrap_err().to_string())
+            .collect();
+        assert_eq!(errors, ["panicked: no input for you", "no input found"]);
+    }
+
+    #[test]
+    fn runs_every_day_whatever_the_jobs() {
+        let days: Vec<_> = days::DAYS.iter().collect();
+        for jobs in [0, 1, 3, 100] {
+            let runs = run(&days, &[Part::One], jobs, |_| Err(eyre!("no input")));
+            assert_eq!(runs.len(), days.len());
+            assert!(runs
+                .iter()
+                .zip(&days)
+                .all(|(run, day)| run.day == day.number));
+        }
+    }
+
+    #[test]
+    fn summarises_in_aligned_columns() {
+        let runs = [
+            DayRun {
+                day: 5,
+                elapsed: Duration::from_micros(1500),
+                outcome: Ok(vec![
+                    Record::new(5, Part::One, "CMZ", Duration::ZERO),
+                    Record::new(5, Part::Two, "#.\n.#", Duration::ZERO),
+                ]),
+            },
+            DayRun {
+                day: 12,
+                elapsed: Duration::from_millis(20),
+                outcome: Err(eyre!("panicked: oops")),
+            },
+        ];
+        assert_eq!(
+            summary(&runs, &Part::BOTH),
+            "day  part 1  part 2      time  status\n  \
+               5  CMZ     #. / .#   1.50ms  ok\n \
+              12  -       -        20.00ms  FAILED, panicked: oops\n",
+        );
+    }
+}
@@ -0,0 +1,282 @@
+//! Small parsers for the shapes puzzle inputs come in, which report where they went wrong as a
+//! [`ParseError`].
+//!
+//! Most inputs are a list of lines that each follow the same pattern, so the parsers work on a
+//! [`Line`] at a time: a cursor that each step consumes from, like
+//!
+//! ```
+//! # use aoc2022::parsing::Line;
+//! let mut line = Line::new(5, 0, "move 3 from 1 to 2");
+//! line.keyword("move ")?;
+//! let count: usize = line.integer("a number of crates")?;
+//! line.keyword(" from ")?;
+//! # Ok::<(), aoc2022::ParseError>(())
+//! ```
+
+use crate::{input::Input, ParseError};
+use std::str::FromStr;
+
+/// A single line of input, which parsers consume from the front of.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Line<'a> {
+    day: u8,
+    idx: usize,
+    line: &'a str,
+    rest: &'a str,
+}
+
+impl<'a> Line<'a> {
+    /// Starts parsing `line`, the 0-based line `idx` of the input of `day`.
+    pub fn new(day: u8, idx: usize, line: &'a str) -> Self {
+        Line {
+            day,
+            idx,
+            line,
+            rest: line,
+        }
+    }
+
+    /// What has not been parsed yet.
+    pub fn rest(&self) -> &'a str {
+        self.rest
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.rest.is_empty()
+    }
+
+    /// An error for `found`, which should be a part of this line.
+    pub fn error(&self, found: &'a str, expected: impl Into<String>) -> ParseError {
+        ParseError::new(self.day, self.idx, self.line, found, expected)
+    }
+
+    /// An error for the next character, or for the line ending too early.
+    pub fn expected(&self, expected: impl Into<String>) -> ParseError {
+        let next = self.rest.chars().next().map_or(0, char::len_utf8);
+        self.error(&self.rest[..next], expected)
+    }
+
+    /// Consumes `literal` if the rest of the line starts with it, returning whether it did.
+    pub fn eat(&mut self, literal: &str) -> bool {
+        match self.rest.strip_prefix(literal) {
+            Some(rest) => {
+                self.rest = rest;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Consumes `literal`, which has to come next.
+    pub fn keyword(&mut self, literal: &str) -> Result<(), ParseError> {
+        if self.eat(literal) {
+            return Ok(());
+        }
+        Err(self.expected(format!("`{literal}`")))
+    }
+
+    /// Consumes whichever of `options` comes next, returning its index.
+    pub fn one_of(&mut self, options: &[&str]) -> Result<usize, ParseError> {
+        if let Some(idx) = options.iter().position(|option| self.eat(option)) {
+            return Ok(idx);
+        }
+        let quoted: Vec<_> = options.iter().map(|option| format!("`{option}`")).collect();
+        let expected = match quoted.split_last() {
+            Some((last, [])) => last.clone(),
+            Some((last, rest)) => format!("{} or {last}", rest.join(", ")),
+            None => "nothing".to_owned(),
+        };
+        Err(self.expected(expected))
+    }
+
+    /// Consumes characters for as long as they match.
+    pub fn take_while(&mut self, mut matches: impl FnMut(char) -> bool) -> &'a str {
+        let end = self
+            .rest
+            .char_indices()
+            .find(|&(_, c)| !matches(c))
+            .map_or(self.rest.len(), |(idx, _)| idx);
+        let (taken, rest) = self.rest.split_at(end);
+        self.rest = rest;
+        taken
+    }
+
+    /// Consumes everything that is left.
+    pub fn take_rest(&mut self) -> &'a str {
+        std::mem::take(&mut self.rest)
+    }
+
+    /// Consumes any whitespace, returning it.
+    pub fn spaces(&mut self) -> &'a str {
+        self.take_while(char::is_whitespace)
+    }
+
+    /// Skips whitespace at the start of what is left, and ignores it at the end.
+    pub fn trim(&mut self) {
+        self.rest = self.rest.trim();
+    }
+
+    /// Consumes a whole word of letters, digits and underscores, and parses it as an integer. A
+    /// word that isn't one, or that doesn't fit in `T`, is an error expecting `expected`.
+    pub fn integer<T: FromStr>(&mut self, expected: &str) -> Result<T, ParseError> {
+        let start = *self;
+        let sign = if self.eat("-") { 1 } else { 0 };
+        let digits = self.take_while(|c| c.is_alphanumeric() || c == '_');
+        let word = &start.rest[..sign + digits.len()];
+        if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+            *self = start;
+            return Err(self.error(word, expected));
+        }
+        word.parse().map_err(|_| {
+            *self = start;
+            self.error(word, expected)
+        })
+    }
+
+    /// Parses one or more items, with a separator between each, which `separator` consumes and
+    /// returns whether it found.
+    pub fn separated<T>(
+        &mut self,
+        mut separator: impl FnMut(&mut Self) -> bool,
+        mut item: impl FnMut(&mut Self) -> Result<T, ParseError>,
+    ) -> Result<Vec<T>, ParseError> {
+        let mut items = vec![item(self)?];
+        while separator(self) {
+            items.push(item(self)?);
+        }
+        Ok(items)
+    }
+
+    /// Runs a parser, and also returns what it consumed, for later errors to point at.
+    pub fn spanned<T>(
+        &mut self,
+        parser: impl FnOnce(&mut Self) -> Result<T, ParseError>,
+    ) -> Result<(T, &'a str), ParseError> {
+        let start = self.rest;
+        let parsed = parser(self)?;
+        Ok((parsed, &start[..start.len() - self.rest.len()]))
+    }
+
+    /// Checks that the whole line has been parsed.
+    pub fn end(&self) -> Result<(), ParseError> {
+        if self.rest.is_empty() {
+            return Ok(());
+        }
+        Err(self.error(self.rest, "the end of the line"))
+    }
+}
+
+/// Every line of the input, to parse one at a time.
+pub fn lines<'a>(input: &'a Input<'_>, day: u8) -> impl Iterator<Item = Line<'a>> {
+    input
+        .lines()
+        .enumerate()
+        .map(move |(idx, line)| Line::new(day, idx, line))
+}
+
+/// Parses every line of the input with `parser`, which has to consume all of it.
+pub fn each_line<'a, T>(
+    input: &'a Input<'_>,
+    day: u8,
+    mut parser: impl FnMut(&mut Line<'a>) -> Result<T, ParseError>,
+) -> Result<Vec<T>, ParseError> {
+    lines(input, day)
+        .map(|mut line| {
+            let parsed = parser(&mut line)?;
+            line.end()?;
+            Ok(parsed)
+        })
+        .collect()
+}
+
+/// The lines of the input in groups, which are separated by blank lines.
+pub fn groups<'a>(input: &'a Input<'_>, day: u8) -> impl Iterator<Item = Vec<Line<'a>>> {
+    input.paragraphs().map(move |paragraph| {
+        paragraph
+            .into_iter()
+            .map(|(idx, line)| Line::new(day, idx, line))
+            .collect()
+    })
+}
+
+/// Parses a rectangular grid of single digits, like a height map.
+pub fn digit_grid<T: From<u8>>(
+    input: &Input<'_>,
+    day: u8,
+    expected: &str,
+) -> Result<Vec<Vec<T>>, ParseError> {
+    input.grid(day, expected, |c| {
+        c.to_digit(10).map(|digit| T::from(digit as u8))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    fn position(err: ParseError) -> (usize, usize, String) {
+        (err.line, err.column, err.found)
+    }
+
+    #[test]
+    fn parses_a_line_step_by_step() {
+        let mut line = Line::new(5, 2, "move 12 from 1 to 3");
+        line.keyword("move ").unwrap();
+        assert_eq!(line.integer::<u8>("a count"), Ok(12));
+        assert!(!line.eat(" to "));
+        assert_eq!(line.one_of(&[" to ", " from "]), Ok(1));
+        let (from, span) = line.spanned(|line| line.integer::<u8>("a stack")).unwrap();
+        assert_eq!((from, span), (1, "1"));
+        line.keyword(" to ").unwrap();
+        assert_eq!(line.take_rest(), "3");
+        assert_eq!(line.end(), Ok(()));
+    }
+
+    #[test]
+    fn points_errors_at_the_problem() {
+        let mut line = Line::new(1, 0, "12x 300 ");
+        assert_eq!(
+            position(line.integer::<u8>("a number").unwrap_err()),
+            (1, 1, "12x".to_owned())
+        );
+        line.take_while(|c| c != ' ');
+        line.spaces();
+        assert_eq!(
+            position(line.integer::<u8>("a small number").unwrap_err()),
+            (1, 5, "300".to_owned())
+        );
+        assert_eq!(line.rest(), "300 ");
+
+        let err = line.one_of(&["A", "B", "C"]).unwrap_err();
+        assert_eq!(err.expected, "`A`, `B` or `C`");
+        assert_eq!(position(err), (1, 5, "3".to_owned()));
+        line.trim();
+        line.integer::<u16>("a number").unwrap();
+        assert_eq!(line.end(), Ok(()));
+        assert_eq!(
+            position(line.keyword("!").unwrap_err()),
+            (1, 8, String::new())
+        );
+    }
+
+    #[test]
+    fn parses_lists_lines_and_groups() {
+        let mut line = Line::new(1, 0, "-1,2,3");
+        let numbers = line.separated(|line| line.eat(","), |line| line.integer::<i8>("a number"));
+        assert_eq!(numbers, Ok(vec![-1, 2, 3]));
+
+        let input = Input::new("1\n2\n\n3\n");
+        assert_eq!(
+            each_line(&input, 1, |line| line.integer::<u8>("a number")),
+            Err(Line::new(1, 2, "").error("", "a number")),
+        );
+        let groups: Vec<Vec<_>> = groups(&input, 1)
+            .map(|group| group.iter().map(Line::rest).collect())
+            .collect();
+        assert_eq!(groups, [vec!["1", "2"], vec!["3"]]);
+
+        let grid: Vec<Vec<u8>> = digit_grid(&Input::new("12\n34\n"), 8, "a digit").unwrap();
+        assert_eq!(grid, [[1, 2], [3, 4]]);
+    }
+}
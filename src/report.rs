@@ -0,0 +1,220 @@
+use crate::days::{
+    day1::{self, Calories, Elf, Stats},
+    day2::{self, Round, Rules},
+    Day,
+};
+use eyre::{bail, eyre, Result};
+use num_bigint::BigInt;
+use num_traits::ToPrimitive as _;
+use std::fmt::Write as _;
+
+/// How many buckets the histograms split the range of values into.
+const BUCKETS: u64 = 10;
+/// How many characters wide the longest bar of a histogram is.
+const BAR_WIDTH: usize = 40;
+
+/// Describes a day's parsed input in more depth than its answers do, for the days that have a
+/// report. `top` limits how many entries any ranking lists.
+pub fn report(day: &Day, input: &str, top: usize) -> Result<String> {
+    let parsed = day.parse(input)?;
+    match day.number {
+        1 => {
+            let elves = parsed
+                .downcast_ref::<Vec<Elf<u128>>>()
+                .expect("day 1 parses into elves");
+            elf_report(elves, top)
+        }
+        2 => {
+            let rounds = parsed
+                .downcast_ref::<Vec<Round>>()
+                .expect("day 2 parses into rounds");
+            guide_report(rounds, top)
+        }
+        number => bail!("day {number} has no report"),
+    }
+}
+
+fn elf_report<T: Calories>(elves: &[Elf<T>], top: usize) -> Result<String> {
+    let stats = Stats::new(elves).ok_or_else(|| eyre!("there are no elves to report on"))?;
+    let mut report = String::new();
+
+    let rows = [
+        ("elves", stats.count.to_string()),
+        ("min", stats.min.to_string()),
+        ("max", stats.max.to_string()),
+        ("mean", format!("{:.1}", stats.mean)),
+        ("median", format!("{:.1}", stats.median)),
+        ("std dev", format!("{:.1}", stats.std_dev)),
+        ("p10", stats.percentile(10.0).to_string()),
+        ("p25", stats.percentile(25.0).to_string()),
+        ("p75", stats.percentile(75.0).to_string()),
+        ("p90", stats.percentile(90.0).to_string()),
+        ("p99", stats.percentile(99.0).to_string()),
+    ];
+    let width = rows.iter().map(|(_, value)| value.len()).max().unwrap_or(0);
+    for (name, value) in rows {
+        writeln!(report, "{name:<7}  {value:>width$}")?;
+    }
+
+    let ranking = day1::ranking(elves);
+    writeln!(report, "\n{:>4}  {:>5}  {:>width$}", "rank", "elf", "total")?;
+    for rank in ranking.iter().take(top) {
+        // Elves are numbered from 1, like lines, to find them in the input.
+        writeln!(
+            report,
+            "{:>4}  {:>5}  {:>width$}",
+            rank.rank,
+            rank.index + 1,
+            rank.total
+        )?;
+    }
+    if ranking.len() > top {
+        writeln!(report, "(and {} more)", ranking.len() - top)?;
+    }
+
+    let totals: Vec<BigInt> = elves.iter().map(|elf| elf.total().clone().into()).collect();
+    let (min, max) = (stats.min.into(), stats.max.into());
+    write!(report, "\n{}", histogram(&totals, &min, &max))?;
+    Ok(report)
+}
+
+fn guide_report(rounds: &[Round], top: usize) -> Result<String> {
+    let rules = Rules::rock_paper_scissors();
+    let ranked = day2::interpretations(&rules, rounds)?;
+    let (Some(best), Some(worst)) = (ranked.first(), ranked.last()) else {
+        bail!("there are no ways to read the guide");
+    };
+    let width = best.score.to_string().len().max("score".len());
+    let mut report = String::new();
+    writeln!(
+        report,
+        "best   {:>width$}  {}",
+        best.score,
+        best.guide.describe(&rules)?
+    )?;
+    writeln!(
+        report,
+        "worst  {:>width$}  {}",
+        worst.score,
+        worst.guide.describe(&rules)?
+    )?;
+
+    writeln!(report, "\n{:>4}  {:>width$}  reading", "rank", "score")?;
+    let mut rank = 0;
+    for (idx, interpretation) in ranked.iter().enumerate().take(top) {
+        // Readings that score the same share a rank.
+        if idx == 0 || ranked[idx - 1].score != interpretation.score {
+            rank = idx + 1;
+        }
+        writeln!(
+            report,
+            "{rank:>4}  {:>width$}  {}",
+            interpretation.score,
+            interpretation.guide.describe(&rules)?
+        )?;
+    }
+    if ranked.len() > top {
+        writeln!(report, "(and {} more)", ranked.len() - top)?;
+    }
+    Ok(report)
+}
+
+/// Draws a horizontal bar for each of up to [`BUCKETS`] equal ranges between `min` and `max`, as
+/// long as how many values fall into it. The values are big integers, so that totals of any width
+/// can be bucketed without overflowing.
+fn histogram(values: &[BigInt], min: &BigInt, max: &BigInt) -> String {
+    let span: BigInt = max - min + 1;
+    let buckets = span.clone().min(BigInt::from(BUCKETS));
+    let bucket = |value: &BigInt| {
+        ((value - min) * &buckets / &span)
+            .to_usize()
+            .expect("there are only a few buckets")
+    };
+    let from = |bucket: usize| min + BigInt::from(bucket) * &span / &buckets;
+
+    let mut counts = vec![0usize; bucket(max) + 1];
+    for value in values {
+        counts[bucket(value)] += 1;
+    }
+    let most = counts.iter().copied().max().unwrap_or(0).max(1);
+    let width = max.to_string().len();
+    let mut histogram = String::new();
+    for (idx, count) in counts.into_iter().enumerate() {
+        let to = from(idx + 1) - 1;
+        let bar = "#".repeat((count * BAR_WIDTH).div_ceil(most));
+        let line = format!(
+            "{:>width$} - {to:>width$} | {bar:<BAR_WIDTH$} {count}",
+            from(idx)
+        );
+        histogram.push_str(&line);
+        histogram.push('\n');
+    }
+    histogram
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::days;
+    use pretty_assertions::assert_eq;
+
+    fn histogram<const N: usize>(values: [u64; N], min: u64, max: u64) -> String {
+        super::histogram(&values.map(BigInt::from), &min.into(), &max.into())
+    }
+
+    #[test]
+    fn draws_histograms() {
+        assert_eq!(
+            histogram([0, 5, 9, 10, 19], 0, 19),
+            format!(
+                " 0 -  1 | {bar:<40} 1\n 2 -  3 | {none:<40} 0\n 4 -  5 | {bar:<40} 1\n \
+                 6 -  7 | {none:<40} 0\n 8 -  9 | {bar:<40} 1\n10 - 11 | {bar:<40} 1\n\
+                 12 - 13 | {none:<40} 0\n14 - 15 | {none:<40} 0\n16 - 17 | {none:<40} 0\n\
+                 18 - 19 | {bar:<40} 1\n",
+                bar = "#".repeat(40),
+                none = "",
+            )
+        );
+        assert_eq!(
+            histogram([7, 7], 7, 7),
+            format!("7 - 7 | {} 2\n", "#".repeat(40))
+        );
+    }
+
+    #[test]
+    fn reports_on_day_1() {
+        let input = "1000\n2000\n3000\n\n4000\n\n5000\n6000\n\n7000\n8000\n9000\n\n10000\n";
+        let text = report(days::get(1).unwrap(), input, 2).unwrap();
+        let lines: Vec<_> = text.lines().collect();
+        assert_eq!(lines[..2], ["elves          5", "min         4000"]);
+        assert_eq!(
+            lines[12..16],
+            [
+                "rank    elf    total",
+                "   1      4    24000",
+                "   2      3    11000",
+                "(and 3 more)"
+            ]
+        );
+
+        assert!(report(days::get(3).unwrap(), "vJrwpWtwJgWrhcsFMMfFFhFp\n", 2).is_err());
+    }
+
+    #[test]
+    fn reports_on_day_2() {
+        let text = report(days::get(2).unwrap(), "A Y\nB X\nC Z\n", 3).unwrap();
+        assert_eq!(
+            text.lines().collect::<Vec<_>>(),
+            [
+                "best      24  X Scissors, Y Paper, Z Rock",
+                "worst      6  X Lose, Y Lose, Z Lose",
+                "",
+                "rank  score  reading",
+                "   1     24  X Scissors, Y Paper, Z Rock",
+                "   1     24  X Win, Y Win, Z Win",
+                "   3     23  X Scissors, Y Paper, Z Scissors",
+                "(and 51 more)",
+            ]
+        );
+    }
+}
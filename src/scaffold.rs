@@ -0,0 +1,346 @@
+use crate::{
+    answers::{Answers, ANSWERS_FILE},
+    fixtures::{self, FIXTURES_DIR},
+};
+use eyre::{bail, eyre, Result, WrapErr as _};
+use std::{
+    fs,
+    ops::Range,
+    path::{Path, PathBuf},
+};
+
+/// The skeleton of a new day, with `{N}` standing in for its number.
+const TEMPLATE: &str = r#"use crate::{input::Input, Answer, ParseError, Solution};
+use eyre::{bail, Result};
+
+const DAY: u8 = {N};
+
+pub struct Day{N};
+
+impl Solution for Day{N} {
+    type Parsed = Vec<String>;
+
+    fn parse(input: &Input<'_>) -> Result<Self::Parsed, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Parsed) -> Result<Answer> {
+        part1(input).map(Answer::from)
+    }
+
+    fn part2(input: &Self::Parsed) -> Result<Answer> {
+        part2(input).map(Answer::from)
+    }
+}
+
+fn part1(_input: &[String]) -> Result<u64> {
+    bail!("day {N} is not solved yet")
+}
+
+fn part2(_input: &[String]) -> Result<u64> {
+    bail!("day {N} is not solved yet")
+}
+
+fn parse(input: &Input<'_>) -> Result<Vec<String>, ParseError> {
+    if input.as_str().trim().is_empty() {
+        return Err(ParseError::missing(DAY, 0, "", "a puzzle input"));
+    }
+    Ok(input.lines().map(String::from).collect())
+}
+"#;
+
+/// A stand-in for the example of a new day, to replace with the one from the puzzle.
+const EXAMPLE_INPUT: &str = "Paste the example input from the puzzle here.\n";
+
+/// The expectations of the example of a new day, to fill in once the puzzle gives them.
+const EXAMPLE_EXPECTED: &str = "\
+# What the puzzle says the example in example1.txt gives.
+# part1 = \"\"
+# part2 = \"\"
+";
+
+/// Creates a new day in the crate at `root`: its module, its registration in [`crate::days`] and
+/// the answers and examples tests, an empty entry in `answers.toml`, and a stub of its example in
+/// `fixtures/dayN/`, as described in [`crate::fixtures`]. Days are registered in number order,
+/// wherever the new one falls. A day whose `inputs/dayN.txt` doesn't exist yet is registered
+/// without an embedded input, as the `embed-inputs` build would fail to find it, with a comment
+/// saying what to switch it to once it does.
+///
+/// Every file is written next to where it goes first, and only moved into place once all of
+/// them are, so a failure leaves the crate as it was. Existing days are never overwritten.
+/// Returns every file that was created or changed.
+pub fn generate(root: &Path, day: u8) -> Result<Vec<PathBuf>> {
+    if !(1..=25).contains(&day) {
+        bail!("day must be from 1 to 25, not {day}");
+    }
+
+    let module_path = root.join(format!("src/days/day{day}.rs"));
+    if module_path.exists() {
+        bail!("{} already exists", module_path.display());
+    }
+
+    let registry_path = root.join("src/days/mod.rs");
+    let registry = read(&registry_path)?;
+    if registry.contains(&format!("pub mod day{day};")) {
+        bail!(
+            "day {day} is already registered in {}",
+            registry_path.display()
+        );
+    }
+    let entry = if root.join(format!("inputs/day{day}.txt")).exists() {
+        format!("    Day::new::<day{day}::Day{day}>({day}, embedded!({day})),")
+    } else {
+        format!(
+            "    Day::new::<day{day}::Day{day}>({day}, None), \
+             // `embedded!({day})` once inputs/day{day}.txt exists"
+        )
+    };
+    let registry = insert_into_block(
+        &insert_module(&registry, day)?,
+        "pub const DAYS: &[Day] = &[",
+        "];",
+        day,
+        &entry,
+    )?;
+
+    let test_path = root.join("tests/answers.rs");
+    let test = insert_into_block(
+        &read(&test_path)?,
+        "verify! {",
+        "}",
+        day,
+        &format!("    day{day} => {day},"),
+    )?;
+    let examples_path = root.join("tests/examples.rs");
+    let examples = insert_into_block(
+        &read(&examples_path)?,
+        "examples! {",
+        "}",
+        day,
+        &format!("    day{day} => {day},"),
+    )?;
+
+    let answers_path = root.join(ANSWERS_FILE);
+    let mut answers = Answers::load(&answers_path)?;
+    answers.add_day(day);
+
+    let fixtures_dir = fixtures::day_dir(&root.join(FIXTURES_DIR), day);
+    let example_input_path = fixtures_dir.join("example1.txt");
+    let example_expected_path = fixtures_dir.join("example1.toml");
+    for path in [&example_input_path, &example_expected_path] {
+        if path.exists() {
+            bail!("{} already exists", path.display());
+        }
+    }
+    fs::create_dir_all(&fixtures_dir)
+        .wrap_err_with(|| format!("could not create {}", fixtures_dir.display()))?;
+
+    let files = vec![
+        (module_path, TEMPLATE.replace("{N}", &day.to_string())),
+        (registry_path, registry),
+        (test_path, test),
+        (examples_path, examples),
+        (example_input_path, EXAMPLE_INPUT.to_owned()),
+        (example_expected_path, EXAMPLE_EXPECTED.to_owned()),
+        (answers_path, toml::to_string(&answers)?),
+    ];
+    if let Err(err) = write_all(&files) {
+        // Only removed if nothing else was in it.
+        let _ = fs::remove_dir(&fixtures_dir);
+        return Err(err);
+    }
+    Ok(files.into_iter().map(|(path, _)| path).collect())
+}
+
+fn read(path: &Path) -> Result<String> {
+    fs::read_to_string(path).wrap_err_with(|| format!("could not read {}", path.display()))
+}
+
+/// Where a file is written before it is moved into place.
+fn staging_path(path: &Path) -> PathBuf {
+    let mut name = path.file_name().unwrap_or_default().to_owned();
+    name.push(".new");
+    path.with_file_name(name)
+}
+
+/// Writes every file to its staging path, then renames them all into place, or removes any that
+/// were written if one of them can't be.
+fn write_all(files: &[(PathBuf, String)]) -> Result<()> {
+    let mut staged = Vec::with_capacity(files.len());
+    for (path, contents) in files {
+        let staging = staging_path(path);
+        if let Err(err) = fs::write(&staging, contents) {
+            for staging in staged {
+                let _ = fs::remove_file(staging);
+            }
+            return Err(err).wrap_err_with(|| format!("could not write {}", path.display()));
+        }
+        staged.push(staging);
+    }
+    for ((path, _), staging) in files.iter().zip(staged) {
+        fs::rename(&staging, path)
+            .wrap_err_with(|| format!("could not move {} into place", path.display()))?;
+    }
+    Ok(())
+}
+
+/// The number of the day a line in a list of days is about, from the first `day<N>` in it.
+fn day_of(line: &str) -> Option<u8> {
+    let (_, rest) = line.split_once("day")?;
+    let digits = &rest[..rest.len() - rest.trim_start_matches(|c: char| c.is_ascii_digit()).len()];
+    digits.parse().ok()
+}
+
+/// Inserts `line` into the list of days in `lines[list]`, before the first later day, so the list
+/// stays in order.
+fn insert_in_order<'a>(lines: &mut Vec<&'a str>, list: Range<usize>, day: u8, line: &'a str) {
+    let idx = lines[list.clone()]
+        .iter()
+        .position(|l| day_of(l).is_some_and(|other| other > day))
+        .map_or(list.end, |idx| list.start + idx);
+    lines.insert(idx, line);
+}
+
+/// Declares the module of `day` among the others, which are declared one after another.
+fn insert_module(source: &str, day: u8) -> Result<String> {
+    const PREFIX: &str = "pub mod day";
+    let mut lines: Vec<&str> = source.lines().collect();
+    let first = lines
+        .iter()
+        .position(|l| l.starts_with(PREFIX))
+        .ok_or_else(|| eyre!("could not find a line starting with {PREFIX:?}"))?;
+    let count = lines[first..]
+        .iter()
+        .take_while(|l| l.starts_with(PREFIX))
+        .count();
+    let line = format!("{PREFIX}{day};");
+    insert_in_order(&mut lines, first..first + count, day, &line);
+    Ok(lines.join("\n") + "\n")
+}
+
+/// Inserts `line` into the list of days between the line equal to `start`, and the first line
+/// equal to `end` after it.
+fn insert_into_block(source: &str, start: &str, end: &str, day: u8, line: &str) -> Result<String> {
+    let mut lines: Vec<&str> = source.lines().collect();
+    let start_idx = lines
+        .iter()
+        .position(|&l| l == start)
+        .ok_or_else(|| eyre!("could not find {start:?}"))?;
+    let end_idx = start_idx
+        + lines[start_idx..]
+            .iter()
+            .position(|&l| l == end)
+            .ok_or_else(|| eyre!("could not find {end:?} after {start:?}"))?;
+    insert_in_order(&mut lines, start_idx + 1..end_idx, day, line);
+    Ok(lines.join("\n") + "\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::days::Part;
+    use pretty_assertions::assert_eq;
+
+    /// Copies the files `generate` touches into a scratch crate.
+    fn scratch() -> tempfile::TempDir {
+        let dir = tempfile::tempdir().unwrap();
+        let real = Path::new(env!("CARGO_MANIFEST_DIR"));
+        for file in [
+            "src/days/mod.rs",
+            "tests/answers.rs",
+            "tests/examples.rs",
+            ANSWERS_FILE,
+        ] {
+            let to = dir.path().join(file);
+            fs::create_dir_all(to.parent().unwrap()).unwrap();
+            fs::copy(real.join(file), to).unwrap();
+        }
+        dir
+    }
+
+    #[test]
+    fn generates_and_registers_day() {
+        let dir = scratch();
+        let root = dir.path();
+        generate(root, 20).unwrap();
+
+        let module = fs::read_to_string(root.join("src/days/day20.rs")).unwrap();
+        assert!(module.contains("pub struct Day20;"));
+        assert!(module.contains("bail!(\"day 20 is not solved yet\")"));
+        assert!(!module.contains("todo!"));
+        let fixtures = fixtures::load(&root.join(FIXTURES_DIR), 20).unwrap();
+        assert_eq!(fixtures.len(), 1);
+        assert_eq!(fixtures[0].expected, fixtures::Expected::default());
+        let registry = fs::read_to_string(root.join("src/days/mod.rs")).unwrap();
+        assert!(registry.contains("\npub mod day20;\n"));
+        assert!(registry.contains(
+            "    Day::new::<day20::Day20>(20, None), \
+             // `embedded!(20)` once inputs/day20.txt exists\n];"
+        ));
+        let test = fs::read_to_string(root.join("tests/answers.rs")).unwrap();
+        assert!(test.contains("    day20 => 20,\n}"));
+        let examples = fs::read_to_string(root.join("tests/examples.rs")).unwrap();
+        assert!(examples.contains("    day20 => 20,\n}"));
+        let answers = Answers::load(&root.join(ANSWERS_FILE)).unwrap();
+        assert_eq!(answers.get(20, Part::One), None);
+        assert!(fs::read_to_string(root.join(ANSWERS_FILE))
+            .unwrap()
+            .contains("[20]"));
+    }
+
+    #[test]
+    fn refuses_to_overwrite() {
+        let dir = scratch();
+        let root = dir.path();
+        generate(root, 20).unwrap();
+        fs::write(root.join("src/days/day20.rs"), "// mine").unwrap();
+
+        assert!(generate(root, 20).is_err());
+        assert_eq!(
+            fs::read_to_string(root.join("src/days/day20.rs")).unwrap(),
+            "// mine"
+        );
+        // Registered days are refused even without a module, and leave nothing behind.
+        assert!(generate(root, 1).is_err());
+        assert!(!root.join("src/days/day1.rs").exists());
+    }
+
+    #[test]
+    fn registers_days_in_order() {
+        let dir = scratch();
+        let root = dir.path();
+        generate(root, 12).unwrap();
+        // Days with an input to embed embed it.
+        fs::create_dir(root.join("inputs")).unwrap();
+        fs::write(root.join("inputs/day10.txt"), "").unwrap();
+        generate(root, 10).unwrap();
+
+        let registry = fs::read_to_string(root.join("src/days/mod.rs")).unwrap();
+        assert!(registry.contains("pub mod day8;\npub mod day10;\npub mod day12;\n"));
+        assert!(registry.contains(
+            "(8, embedded!(8)),\n    Day::new::<day10::Day10>(10, embedded!(10)),\n    \
+             Day::new::<day12::Day12>(12, None), \
+             // `embedded!(12)` once inputs/day12.txt exists\n];"
+        ));
+        let examples = fs::read_to_string(root.join("tests/examples.rs")).unwrap();
+        assert!(examples.contains("    day8 => 8,\n    day10 => 10,\n    day12 => 12,\n}"));
+    }
+
+    #[test]
+    fn writes_nothing_if_anything_fails() {
+        let dir = scratch();
+        let root = dir.path();
+        let registry = fs::read_to_string(root.join("src/days/mod.rs")).unwrap();
+        // The answers are written last, and can't be with a directory in the way.
+        fs::create_dir(staging_path(&root.join(ANSWERS_FILE))).unwrap();
+
+        assert!(generate(root, 20).is_err());
+        assert!(!root.join("src/days/day20.rs").exists());
+        assert!(!staging_path(&root.join("src/days/day20.rs")).exists());
+        assert!(!root.join("fixtures/day20").exists());
+        assert_eq!(
+            fs::read_to_string(root.join("src/days/mod.rs")).unwrap(),
+            registry
+        );
+    }
+}
@@ -0,0 +1,13 @@
+use crate::{input::Input, Answer, ParseError};
+use eyre::Result;
+
+/// A single day's puzzle: how to parse its input, and how to solve both parts from that.
+pub trait Solution {
+    /// The parsed form of the puzzle input, shared by both parts.
+    type Parsed;
+
+    /// Parses the input, which has already had its line endings normalised.
+    fn parse(input: &Input<'_>) -> Result<Self::Parsed, ParseError>;
+    fn part1(parsed: &Self::Parsed) -> Result<Answer>;
+    fn part2(parsed: &Self::Parsed) -> Result<Answer>;
+}
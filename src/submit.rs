@@ -0,0 +1,398 @@
+use crate::{days::Part, http::Http, input::fetch::YEAR, Answer};
+use eyre::{eyre, Result, WrapErr as _};
+use num_bigint::BigInt;
+use serde::{Deserialize, Serialize};
+use std::{
+    fmt,
+    fs::{self, OpenOptions},
+    io::{self, Write as _},
+    path::{Path, PathBuf},
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+/// Where every submission is logged, relative to the current directory, which `aoc` expects to be
+/// the crate root.
+pub const SUBMISSIONS_FILE: &str = "submissions.jsonl";
+
+/// What the website said about a submitted answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "outcome", rename_all = "snake_case")]
+pub enum Outcome {
+    Correct,
+    TooHigh,
+    TooLow,
+    /// Wrong, without a hint as to which way.
+    Wrong,
+    /// An answer was submitted too recently, so this one wasn't checked.
+    Wait {
+        seconds: Option<u64>,
+    },
+    /// The part is not the one being solved, because it was already solved, or part 1 hasn't
+    /// been yet, so this one wasn't checked.
+    WrongLevel,
+}
+
+impl Outcome {
+    /// Works out the outcome from the HTML page the website responds with.
+    pub fn interpret(body: &str) -> Result<Self> {
+        if body.contains("That's the right answer") {
+            Ok(Outcome::Correct)
+        } else if body.contains("That's not the right answer") {
+            Ok(if body.contains("your answer is too high") {
+                Outcome::TooHigh
+            } else if body.contains("your answer is too low") {
+                Outcome::TooLow
+            } else {
+                Outcome::Wrong
+            })
+        } else if body.contains("You gave an answer too recently") {
+            Ok(Outcome::Wait {
+                seconds: parse_wait(body),
+            })
+        } else if body.contains("You don't seem to be solving the right level") {
+            Ok(Outcome::WrongLevel)
+        } else {
+            Err(eyre!(
+                "could not make sense of the response: {}",
+                body.trim()
+            ))
+        }
+    }
+}
+
+impl fmt::Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Outcome::Correct => f.write_str("correct"),
+            Outcome::TooHigh => f.write_str("too high"),
+            Outcome::TooLow => f.write_str("too low"),
+            Outcome::Wrong => f.write_str("wrong"),
+            Outcome::Wait { seconds: Some(s) } => write!(f, "wait {s}s before trying again"),
+            Outcome::Wait { seconds: None } => f.write_str("wait before trying again"),
+            Outcome::WrongLevel => f.write_str("not for the part being solved"),
+        }
+    }
+}
+
+/// Finds the time left in e.g. `You have 1m 5s left to wait.`
+fn parse_wait(body: &str) -> Option<u64> {
+    let start = body.find("You have ")? + "You have ".len();
+    let end = start + body[start..].find(" left to wait")?;
+    body[start..end]
+        .split_whitespace()
+        .map(|unit| match unit.split_at(unit.len().checked_sub(1)?) {
+            (n, "h") => n.parse::<u64>().ok().map(|n| n * 3600),
+            (n, "m") => n.parse::<u64>().ok().map(|n| n * 60),
+            (n, "s") => n.parse::<u64>().ok(),
+            _ => None,
+        })
+        .sum()
+}
+
+/// A single submitted answer, and what came of it.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Attempt {
+    pub day: u8,
+    pub part: u8,
+    pub answer: String,
+    /// When it was submitted, in seconds since the Unix epoch.
+    pub at: u64,
+    #[serde(flatten)]
+    pub outcome: Outcome,
+}
+
+/// Why an answer was not submitted.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Refusal {
+    AlreadySolved { answer: String },
+    AlreadyTried { outcome: Outcome },
+    TooHigh { bound: String },
+    TooLow { bound: String },
+    MustWait { seconds: u64 },
+}
+
+impl fmt::Display for Refusal {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Refusal::AlreadySolved { answer } => write!(f, "already solved, with {answer}"),
+            Refusal::AlreadyTried { outcome } => {
+                write!(f, "already submitted, and it was {outcome}")
+            }
+            Refusal::TooHigh { bound } => write!(f, "{bound} was already too high"),
+            Refusal::TooLow { bound } => write!(f, "{bound} was already too low"),
+            Refusal::MustWait { seconds } => write!(f, "must wait {seconds}s before trying again"),
+        }
+    }
+}
+
+/// Every answer submitted so far, stored as one JSON object per line.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Log {
+    pub attempts: Vec<Attempt>,
+}
+
+impl Log {
+    /// Loads the log from a file, which is treated as empty if it does not exist yet.
+    pub fn load(path: &Path) -> Result<Self> {
+        let contents = match fs::read_to_string(path) {
+            Ok(contents) => contents,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(Log::default()),
+            Err(err) => {
+                return Err(err)
+                    .wrap_err_with(|| format!("could not read submissions {}", path.display()))
+            }
+        };
+        let attempts = contents
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .enumerate()
+            .map(|(idx, line)| {
+                serde_json::from_str(line).wrap_err_with(|| {
+                    format!(
+                        "invalid submission on line {} of {}",
+                        idx + 1,
+                        path.display()
+                    )
+                })
+            })
+            .collect::<Result<_>>()?;
+        Ok(Log { attempts })
+    }
+
+    /// Appends an attempt to both this log and the file it was loaded from.
+    pub fn append(&mut self, path: &Path, attempt: Attempt) -> Result<()> {
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path)
+            .wrap_err_with(|| format!("could not open submissions {}", path.display()))?;
+        writeln!(file, "{}", serde_json::to_string(&attempt)?)?;
+        self.attempts.push(attempt);
+        Ok(())
+    }
+
+    /// Checks the log for a reason not to submit an answer: it's solved, the answer is already
+    /// known to be wrong, or the website asked us to wait.
+    pub fn check(&self, day: u8, part: Part, answer: &Answer, now: u64) -> Result<(), Refusal> {
+        let attempts = self
+            .attempts
+            .iter()
+            .filter(|a| a.day == day && a.part == part.number());
+
+        let value = match answer {
+            Answer::Integer(n) => Some(n),
+            _ => None,
+        };
+        for attempt in attempts {
+            match attempt.outcome {
+                Outcome::Correct => {
+                    return Err(Refusal::AlreadySolved {
+                        answer: attempt.answer.clone(),
+                    })
+                }
+                Outcome::Wait { seconds } => {
+                    let until = attempt.at + seconds.unwrap_or(60);
+                    if now < until {
+                        return Err(Refusal::MustWait {
+                            seconds: until - now,
+                        });
+                    }
+                }
+                // The answer wasn't checked, so it might still be right.
+                Outcome::WrongLevel => {}
+                _ if *answer == attempt.answer => {
+                    return Err(Refusal::AlreadyTried {
+                        outcome: attempt.outcome,
+                    })
+                }
+                Outcome::TooHigh | Outcome::TooLow => {
+                    let bound = attempt.answer.parse::<BigInt>().ok();
+                    match (value, bound) {
+                        (Some(value), Some(bound))
+                            if attempt.outcome == Outcome::TooHigh && *value >= bound =>
+                        {
+                            return Err(Refusal::TooHigh {
+                                bound: attempt.answer.clone(),
+                            })
+                        }
+                        (Some(value), Some(bound))
+                            if attempt.outcome == Outcome::TooLow && *value <= bound =>
+                        {
+                            return Err(Refusal::TooLow {
+                                bound: attempt.answer.clone(),
+                            })
+                        }
+                        _ => {}
+                    }
+                }
+                Outcome::Wrong => {}
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Submits answers to the website, keeping a log of every attempt.
+pub struct Submitter<H> {
+    http: H,
+    log_path: PathBuf,
+    log: Log,
+}
+
+impl<H: Http> Submitter<H> {
+    pub fn new(http: H, log_path: PathBuf) -> Result<Self> {
+        let log = Log::load(&log_path)?;
+        Ok(Submitter {
+            http,
+            log_path,
+            log,
+        })
+    }
+
+    /// Submits an answer, unless the log shows there is no point in doing so.
+    pub fn submit(&mut self, day: u8, part: Part, answer: &Answer) -> Result<Outcome> {
+        if let Answer::Art(_) = answer {
+            return Err(eyre!(
+                "answers drawn as art have to be read, then submitted by hand"
+            ));
+        }
+        let now = SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs();
+        if let Err(refusal) = self.log.check(day, part, answer, now) {
+            return Err(eyre!("not submitting {answer}: {refusal}"));
+        }
+
+        let body = self.http.post_form(
+            &format!("/{YEAR}/day/{day}/answer"),
+            &[
+                ("level", &part.to_string()),
+                ("answer", &answer.to_string()),
+            ],
+        )?;
+        let outcome = Outcome::interpret(&body)?;
+        self.log.append(
+            &self.log_path,
+            Attempt {
+                day,
+                part: part.number(),
+                answer: answer.to_string(),
+                at: now,
+                outcome,
+            },
+        )?;
+        Ok(outcome)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::http::{stub, Client};
+    use pretty_assertions::assert_eq;
+
+    const WRONG_TOO_HIGH: &str = "<article><p>That's not the right answer; your answer is too \
+        high.  If you're stuck, make sure you're using the full input data.</p></article>";
+    const RIGHT: &str = "<article><p>That's the right answer!  You are one gold star closer to \
+        collecting enough star fruit.</p></article>";
+    const WAIT: &str = "<article><p>You gave an answer too recently; you have to wait after \
+        submitting an answer before trying again.  You have 1m 5s left to wait.</p></article>";
+
+    fn attempt(answer: &str, at: u64, outcome: Outcome) -> Attempt {
+        Attempt {
+            day: 1,
+            part: 1,
+            answer: answer.to_owned(),
+            at,
+            outcome,
+        }
+    }
+
+    #[test]
+    fn interprets_responses() {
+        assert_eq!(Outcome::interpret(RIGHT).unwrap(), Outcome::Correct);
+        assert_eq!(
+            Outcome::interpret(WRONG_TOO_HIGH).unwrap(),
+            Outcome::TooHigh
+        );
+        assert_eq!(
+            Outcome::interpret(WAIT).unwrap(),
+            Outcome::Wait { seconds: Some(65) }
+        );
+        assert!(Outcome::interpret("<html>500</html>").is_err());
+    }
+
+    #[test]
+    fn refuses_known_bad_answers() {
+        let log = Log {
+            attempts: vec![
+                attempt("100", 0, Outcome::TooHigh),
+                attempt("10", 0, Outcome::TooLow),
+                attempt("50", 0, Outcome::Wrong),
+            ],
+        };
+        let check = |n: u64| log.check(1, Part::One, &Answer::from(n), 1000);
+        assert_eq!(
+            check(50),
+            Err(Refusal::AlreadyTried {
+                outcome: Outcome::Wrong
+            })
+        );
+        assert_eq!(
+            check(150),
+            Err(Refusal::TooHigh {
+                bound: "100".into()
+            })
+        );
+        assert_eq!(check(5), Err(Refusal::TooLow { bound: "10".into() }));
+        assert_eq!(check(51), Ok(()));
+        assert_eq!(log.check(1, Part::Two, &Answer::from(150u64), 1000), Ok(()));
+    }
+
+    #[test]
+    fn refuses_while_waiting_and_once_solved() {
+        let log = Log {
+            attempts: vec![attempt("1", 1000, Outcome::Wait { seconds: Some(60) })],
+        };
+        assert_eq!(
+            log.check(1, Part::One, &Answer::from(2u64), 1030),
+            Err(Refusal::MustWait { seconds: 30 })
+        );
+        assert_eq!(log.check(1, Part::One, &Answer::from(2u64), 1060), Ok(()));
+
+        let log = Log {
+            attempts: vec![attempt("1", 0, Outcome::Correct)],
+        };
+        assert_eq!(
+            log.check(1, Part::One, &Answer::from(2u64), 1000),
+            Err(Refusal::AlreadySolved { answer: "1".into() })
+        );
+    }
+
+    #[test]
+    fn submits_and_logs() {
+        let dir = tempfile::tempdir().unwrap();
+        let log_path = dir.path().join(SUBMISSIONS_FILE);
+        let server = stub::serve(vec![(200, WRONG_TOO_HIGH)]);
+        let client = Client::with_base_url(&server.base_url, "abc");
+        let mut submitter = Submitter::new(client, log_path.clone()).unwrap();
+
+        let outcome = submitter
+            .submit(1, Part::Two, &Answer::from(42u64))
+            .unwrap();
+        assert_eq!(outcome, Outcome::TooHigh);
+        let requests = server.requests();
+        assert_eq!(requests[0].path, "/2022/day/1/answer");
+        assert_eq!(requests[0].body, "level=2&answer=42");
+
+        // The log survives a reload, and stops us from trying a higher answer.
+        let mut submitter = Submitter::new(
+            Client::with_base_url(&server.base_url, "abc"),
+            log_path.clone(),
+        )
+        .unwrap();
+        assert_eq!(submitter.log.attempts.len(), 1);
+        assert!(submitter
+            .submit(1, Part::Two, &Answer::from(43u64))
+            .is_err());
+        assert_eq!(Log::load(&log_path).unwrap().attempts.len(), 1);
+    }
+}
@@ -0,0 +1,377 @@
+use crate::{
+    bench::Phase,
+    days::{Day, Part},
+    fixtures::{self, Expected, FIXTURES_DIR},
+    input, parallel,
+};
+use eyre::{bail, Result, WrapErr as _};
+use notify::{Config, Event, PollWatcher, RecursiveMode, Watcher as _};
+use std::{
+    fs,
+    panic::{self, AssertUnwindSafe},
+    path::{self, Path, PathBuf},
+    sync::mpsc::{self, Receiver, RecvTimeoutError},
+    time::{Duration, Instant},
+};
+
+/// How long to wait for more events after a change, so saving a file runs the day once, not for
+/// every write an editor makes.
+const SETTLE: Duration = Duration::from_millis(100);
+
+/// An input to run a day on while watching it: the day's puzzle input, or one of its fixtures.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Source {
+    pub name: String,
+    pub input: String,
+    pub expected: Option<Expected>,
+}
+
+/// Reads the day's puzzle input, if it is saved in `inputs/`, and every one of its fixtures.
+///
+/// Unlike [`input::load`], this never fetches the input, so a change does not hit the website.
+pub fn sources(day: &Day) -> Result<Vec<Source>> {
+    let mut sources = Vec::new();
+    let path = input::default_path(day);
+    if path.exists() {
+        sources.push(Source {
+            name: "input".to_owned(),
+            input: fs::read_to_string(&path)
+                .wrap_err_with(|| format!("could not read input from {}", path.display()))?,
+            expected: None,
+        });
+    }
+    for fixture in fixtures::load(Path::new(FIXTURES_DIR), day.number)? {
+        sources.push(Source {
+            name: fixture.name,
+            input: fixture.input,
+            expected: Some(fixture.expected),
+        });
+    }
+    Ok(sources)
+}
+
+/// What came of a single phase of running a day on a source: `ok` for parsing, the answer for a
+/// part, or why it failed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Outcome {
+    pub source: String,
+    pub phase: Phase,
+    pub result: String,
+}
+
+/// Parses every source and solves both parts of it. Nothing here is fatal: errors, panics and
+/// answers that differ from a fixture's are all reported in the outcomes.
+pub fn evaluate(day: &Day, sources: &[Source]) -> Vec<Outcome> {
+    let mut outcomes = Vec::new();
+    for source in sources {
+        let expected = source.expected.clone().unwrap_or_default();
+        let mut push = |phase, result| {
+            outcomes.push(Outcome {
+                source: source.name.clone(),
+                phase,
+                result,
+            })
+        };
+
+        let parsed = panic::catch_unwind(AssertUnwindSafe(|| day.parse(&source.input)));
+        let parsed = match (parsed, &expected.parse_error) {
+            (Ok(Ok(parsed)), None) => parsed,
+            (Ok(Ok(_)), Some(expected)) => {
+                push(
+                    Phase::Parse,
+                    format!("parsed, but expected an error with {expected:?}"),
+                );
+                continue;
+            }
+            (Ok(Err(err)), Some(expected)) if err.to_string().contains(expected.as_str()) => {
+                push(Phase::Parse, "failed as expected".to_owned());
+                continue;
+            }
+            (Ok(Err(err)), _) => {
+                push(Phase::Parse, format!("error: {err}"));
+                continue;
+            }
+            (Err(payload), _) => {
+                let message = parallel::panic_message(&*payload);
+                push(Phase::Parse, format!("panicked: {message}"));
+                continue;
+            }
+        };
+        push(Phase::Parse, "ok".to_owned());
+
+        for (phase, part) in [(Phase::Part1, Part::One), (Phase::Part2, Part::Two)] {
+            let answer = panic::catch_unwind(AssertUnwindSafe(|| day.solve(&*parsed, part)));
+            let result = match (answer, expected.get(part)) {
+                (Ok(Ok(answer)), Some(expected)) if answer != *expected => {
+                    format!("{answer} (expected {expected})")
+                }
+                (Ok(Ok(answer)), _) => answer.to_string(),
+                (Ok(Err(err)), _) => format!("error: {err}"),
+                (Err(payload), _) => format!("panicked: {}", parallel::panic_message(&*payload)),
+            };
+            push(phase, result);
+        }
+    }
+    outcomes
+}
+
+/// How an outcome differs from the last time the day was run.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Change<'a> {
+    Same(&'a Outcome),
+    Changed { before: &'a str, now: &'a Outcome },
+    Added(&'a Outcome),
+    Removed(&'a Outcome),
+}
+
+/// Compares the outcomes of two runs, in the order of the current one, with those that are gone
+/// at the end.
+pub fn diff<'a>(before: &'a [Outcome], now: &'a [Outcome]) -> Vec<Change<'a>> {
+    let find = |outcomes: &'a [Outcome], o: &Outcome| {
+        outcomes
+            .iter()
+            .find(|other| other.source == o.source && other.phase == o.phase)
+    };
+    let mut changes: Vec<_> = now
+        .iter()
+        .map(|outcome| match find(before, outcome) {
+            Some(old) if old.result == outcome.result => Change::Same(outcome),
+            Some(old) => Change::Changed {
+                before: &old.result,
+                now: outcome,
+            },
+            None => Change::Added(outcome),
+        })
+        .collect();
+    changes.extend(
+        before
+            .iter()
+            .filter(|outcome| find(now, outcome).is_none())
+            .map(Change::Removed),
+    );
+    changes
+}
+
+/// The files a day depends on, which are watched for changes: its input, and its fixtures.
+pub fn watched_paths(day: &Day) -> Vec<PathBuf> {
+    vec![
+        input::default_path(day),
+        fixtures::day_dir(Path::new(FIXTURES_DIR), day.number),
+    ]
+}
+
+/// Waits for changes to a set of files and directories, which need not exist yet.
+///
+/// It relies on the OS telling it about changes when it can, and falls back on polling when it
+/// can't, like in containers without inotify or on some network file systems, where it might not
+/// notice anything otherwise.
+pub struct Watcher {
+    /// Only kept so it keeps watching.
+    _watcher: Box<dyn notify::Watcher>,
+    events: Receiver<notify::Result<Event>>,
+    paths: Vec<PathBuf>,
+    polling: bool,
+}
+
+impl Watcher {
+    /// Starts watching `paths`, polling every `interval` if `poll` is set, or if the OS can't
+    /// watch them itself.
+    pub fn new(paths: &[PathBuf], poll: bool, interval: Duration) -> Result<Self> {
+        let paths = paths
+            .iter()
+            .map(path::absolute)
+            .collect::<Result<Vec<_>, _>>()?;
+        // Watching the parent directories too catches paths that are created, or replaced
+        // rather than written to, like editors tend to do when saving.
+        let mut dirs: Vec<&Path> = paths.iter().filter_map(|path| path.parent()).collect();
+        dirs.sort();
+        dirs.dedup();
+        dirs.retain(|dir| dir.is_dir());
+        if dirs.is_empty() {
+            bail!("none of the paths to watch are in a directory that exists");
+        }
+
+        let (sender, events) = mpsc::channel();
+        if !poll {
+            let native = notify::recommended_watcher(sender.clone()).and_then(|mut watcher| {
+                for dir in &dirs {
+                    watcher.watch(dir, RecursiveMode::Recursive)?;
+                }
+                Ok(watcher)
+            });
+            if let Ok(watcher) = native {
+                return Ok(Watcher {
+                    _watcher: Box::new(watcher),
+                    events,
+                    paths,
+                    polling: false,
+                });
+            }
+        }
+
+        // Modification times are only compared to the second, so the contents are too, to notice
+        // quick successive saves.
+        let config = Config::default()
+            .with_poll_interval(interval)
+            .with_compare_contents(true);
+        let mut watcher = PollWatcher::new(sender, config)?;
+        for dir in &dirs {
+            watcher.watch(dir, RecursiveMode::Recursive)?;
+        }
+        Ok(Watcher {
+            _watcher: Box::new(watcher),
+            events,
+            paths,
+            polling: true,
+        })
+    }
+
+    /// Whether changes are found by polling, rather than being reported by the OS.
+    pub fn polling(&self) -> bool {
+        self.polling
+    }
+
+    /// Blocks until one of the watched paths changes, or until `timeout` is up if one is given.
+    /// Returns whether anything changed.
+    pub fn wait(&self, timeout: Option<Duration>) -> Result<bool> {
+        let deadline = timeout.map(|timeout| Instant::now() + timeout);
+        loop {
+            let event = match deadline {
+                Some(deadline) => {
+                    match self
+                        .events
+                        .recv_timeout(deadline.saturating_duration_since(Instant::now()))
+                    {
+                        Ok(event) => event,
+                        Err(RecvTimeoutError::Timeout) => return Ok(false),
+                        Err(RecvTimeoutError::Disconnected) => {
+                            bail!("stopped watching for changes")
+                        }
+                    }
+                }
+                None => self.events.recv()?,
+            };
+            if self.is_relevant(&event?) {
+                while self.events.recv_timeout(SETTLE).is_ok() {}
+                return Ok(true);
+            }
+        }
+    }
+
+    fn is_relevant(&self, event: &Event) -> bool {
+        !event.kind.is_access()
+            && event
+                .paths
+                .iter()
+                .any(|changed| self.paths.iter().any(|path| changed.starts_with(path)))
+    }
+}
+
+impl std::fmt::Debug for Watcher {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Watcher")
+            .field("paths", &self.paths)
+            .field("polling", &self.polling)
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::days;
+    use pretty_assertions::assert_eq;
+
+    fn outcome(source: &str, phase: Phase, result: &str) -> Outcome {
+        Outcome {
+            source: source.to_owned(),
+            phase,
+            result: result.to_owned(),
+        }
+    }
+
+    #[test]
+    fn evaluates_against_fixtures() {
+        let source = |name: &str, input: &str, expected| Source {
+            name: name.to_owned(),
+            input: input.to_owned(),
+            expected,
+        };
+        let sources = [
+            source("input", "mjqjpqmgbljsphdztnvjfqwrcgsmlb\n", None),
+            source(
+                "wrong",
+                "bvwbjplbgvbhsrlpgdmjqwftvncz\n",
+                Some(Expected {
+                    part1: Some("6".to_owned()),
+                    ..Expected::default()
+                }),
+            ),
+            source(
+                "invalid",
+                "ABC\n",
+                Some(Expected {
+                    parse_error: Some("line 1".to_owned()),
+                    ..Expected::default()
+                }),
+            ),
+        ];
+        assert_eq!(
+            evaluate(days::get(6).unwrap(), &sources),
+            [
+                outcome("input", Phase::Parse, "ok"),
+                outcome("input", Phase::Part1, "7"),
+                outcome("input", Phase::Part2, "19"),
+                outcome("wrong", Phase::Parse, "ok"),
+                outcome("wrong", Phase::Part1, "5 (expected 6)"),
+                outcome("wrong", Phase::Part2, "23"),
+                outcome("invalid", Phase::Parse, "failed as expected"),
+            ]
+        );
+    }
+
+    #[test]
+    fn diffs_against_the_last_run() {
+        let before = [
+            outcome("input", Phase::Part1, "7"),
+            outcome("input", Phase::Part2, "19"),
+            outcome("old", Phase::Parse, "ok"),
+        ];
+        let now = [
+            outcome("input", Phase::Part1, "7"),
+            outcome("input", Phase::Part2, "20"),
+            outcome("new", Phase::Parse, "ok"),
+        ];
+        assert_eq!(
+            diff(&before, &now),
+            [
+                Change::Same(&now[0]),
+                Change::Changed {
+                    before: "19",
+                    now: &now[1],
+                },
+                Change::Added(&now[2]),
+                Change::Removed(&before[2]),
+            ]
+        );
+    }
+
+    #[test]
+    fn polling_notices_changes() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("day1.txt");
+        fs::write(&path, "1\n").unwrap();
+        let watcher = Watcher::new(
+            &[path.clone(), dir.path().join("fixtures")],
+            true,
+            Duration::from_millis(20),
+        )
+        .unwrap();
+        assert!(watcher.polling());
+
+        fs::write(dir.path().join("unrelated.txt"), "2\n").unwrap();
+        assert!(!watcher.wait(Some(Duration::from_millis(200))).unwrap());
+        fs::write(&path, "1\n2\n").unwrap();
+        assert!(watcher.wait(Some(Duration::from_secs(5))).unwrap());
+    }
+}
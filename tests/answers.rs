@@ -0,0 +1,56 @@
+//! Checks every day against its real input and the answer recorded for it in `answers.toml`.
+//!
+//! Days without an input file or a recorded answer are skipped, so this passes on a fresh
+//! checkout. Run `aoc verify --record` to accept new answers.
+
+use aoc2022::{
+    answers::{self, Answers, Verdict, ANSWERS_FILE},
+    days, input,
+};
+use std::path::Path;
+
+fn check(number: u8) {
+    let day = days::get(number).expect("day is not registered");
+    if !input::default_path(day).exists() && day.embedded_input.is_none() {
+        eprintln!("skipping day {number}: no input");
+        return;
+    }
+    let input = input::load(day, None).unwrap();
+    let recorded = Answers::load(Path::new(ANSWERS_FILE)).unwrap();
+
+    for verification in answers::verify(day, &input, &recorded) {
+        let part = verification.part;
+        match verification.outcome {
+            Ok((_, Verdict::Correct)) => {}
+            Ok((answer, Verdict::Wrong { expected })) => {
+                panic!("day {number}, part {part}: got {answer}, but expected {expected}")
+            }
+            Ok((_, Verdict::Unrecorded)) => {
+                eprintln!("skipping day {number}, part {part}: no recorded answer")
+            }
+            Err(err) => panic!("day {number}, part {part}: {err:?}"),
+        }
+    }
+}
+
+macro_rules! verify {
+    ($($name:ident => $day:literal,)*) => {
+        $(
+            #[test]
+            fn $name() {
+                check($day);
+            }
+        )*
+    };
+}
+
+verify! {
+    day1 => 1,
+    day2 => 2,
+    day3 => 3,
+    day4 => 4,
+    day5 => 5,
+    day6 => 6,
+    day7 => 7,
+    day8 => 8,
+}
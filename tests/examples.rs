@@ -0,0 +1,90 @@
+//! Checks every day against the example inputs in `fixtures/dayN/`.
+//!
+//! Each day gets a `parse`, `part1` and `part2` test, which run over all of its fixtures, so
+//! adding a case is a matter of dropping in a `.txt` file and a `.toml` file with what it should
+//! give.
+
+use aoc2022::{
+    days::{self, Part},
+    fixtures::{self, Fixture, FIXTURES_DIR},
+};
+use std::path::Path;
+
+fn fixtures(number: u8) -> Vec<Fixture> {
+    let fixtures = fixtures::load(Path::new(FIXTURES_DIR), number).unwrap();
+    if fixtures.is_empty() {
+        eprintln!("skipping day {number}: no fixtures");
+    }
+    fixtures
+}
+
+fn check_parse(number: u8) {
+    let day = days::get(number).expect("day is not registered");
+    for fixture in fixtures(number) {
+        let name = &fixture.name;
+        match (day.parse(&fixture.input), &fixture.expected.parse_error) {
+            (Ok(_), None) => {}
+            (Ok(_), Some(expected)) => {
+                panic!("day {number}, {name}: parsed, but expected an error with {expected:?}")
+            }
+            (Err(err), None) => panic!("day {number}, {name}: {err:?}"),
+            (Err(err), Some(expected)) => assert!(
+                err.to_string().contains(expected.as_str()),
+                "day {number}, {name}: expected an error with {expected:?}, got {err}",
+            ),
+        }
+    }
+}
+
+fn check_part(number: u8, part: Part) {
+    let day = days::get(number).expect("day is not registered");
+    for fixture in fixtures(number) {
+        let name = &fixture.name;
+        let Some(expected) = fixture.expected.get(part) else {
+            continue;
+        };
+        let answer = day
+            .run(&fixture.input, part)
+            .unwrap_or_else(|err| panic!("day {number}, {name}, part {part}: {err:?}"));
+        assert!(
+            answer == expected,
+            "day {number}, {name}, part {part}: got {answer}, but expected {expected}",
+        );
+    }
+}
+
+macro_rules! examples {
+    ($($name:ident => $day:literal,)*) => {
+        $(
+            mod $name {
+                use super::*;
+
+                #[test]
+                fn parse() {
+                    check_parse($day);
+                }
+
+                #[test]
+                fn part1() {
+                    check_part($day, Part::One);
+                }
+
+                #[test]
+                fn part2() {
+                    check_part($day, Part::Two);
+                }
+            }
+        )*
+    };
+}
+
+examples! {
+    day1 => 1,
+    day2 => 2,
+    day3 => 3,
+    day4 => 4,
+    day5 => 5,
+    day6 => 6,
+    day7 => 7,
+    day8 => 8,
+}
@@ -0,0 +1,36 @@
+//! Replays the inputs that once crashed a fuzz target, from `fuzz/regressions/dayN/`, so they
+//! keep ending in an error rather than a panic without needing a nightly compiler to check.
+
+use aoc2022::days;
+use std::{fs, panic};
+
+const REGRESSIONS_DIR: &str = "fuzz/regressions";
+
+#[test]
+fn regressions_do_not_panic() {
+    let mut panicked = Vec::new();
+    for dir in fs::read_dir(REGRESSIONS_DIR).unwrap() {
+        let dir = dir.unwrap().path();
+        let number: u8 = dir
+            .file_name()
+            .and_then(|name| name.to_str()?.strip_prefix("day")?.parse().ok())
+            .unwrap_or_else(|| panic!("{} is not named after a day", dir.display()));
+        let day = days::get(number).expect("day is not registered");
+
+        for input in fs::read_dir(&dir).unwrap() {
+            let path = input.unwrap().path();
+            let data = fs::read(&path).unwrap();
+            if panic::catch_unwind(|| day.fuzz(&data)).is_err() {
+                panicked.push(path);
+            }
+        }
+    }
+    assert!(
+        panicked.is_empty(),
+        "panicked on {:?}",
+        panicked
+            .iter()
+            .map(|path| path.display())
+            .collect::<Vec<_>>(),
+    );
+}